@@ -0,0 +1,304 @@
+use core::{
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+    mem,
+};
+
+use svm_hasher::SvmBuildHasher;
+
+/// The number of buckets `hashbrown` probes at a time in a program, it uses
+/// its generic implementation on SBF which works on a `usize` at a time.
+pub const SBF_GROUP_WIDTH: usize = 8;
+
+pub type HashMap<K, V> = hashbrown::HashMap<K, V, SvmBuildHasher>;
+
+pub type HashSet<K> = hashbrown::HashSet<K, SvmBuildHasher>;
+
+pub type StdHashMap<K, V> = std::collections::HashMap<K, V, SvmBuildHasher>;
+
+pub type StdHashSet<K> = std::collections::HashSet<K, SvmBuildHasher>;
+
+/// Returns the hash the program computes for `key` in a `svm_hashmap::HashMap`
+/// or a `svm_hashset::HashSet`.
+pub fn hash_key<K: Hash + ?Sized>(key: &K) -> u64 {
+    hash_key_with(&SvmBuildHasher::default(), key)
+}
+
+/// Same as `hash_key`, for a map that uses `build_hasher`.
+pub fn hash_key_with<S: BuildHasher, K: Hash + ?Sized>(build_hasher: &S, key: &K) -> u64 {
+    build_hasher.hash_one(key)
+}
+
+/// Replays the inserts into a `hashbrown` map to find the bucket each key ends
+/// up in, and so the order in which the map iterates over them.
+///
+/// `GROUP_WIDTH` is `SBF_GROUP_WIDTH` for maps in a program, `V` only matters
+/// because `hashbrown` sizes small tables by the size of the entries.
+pub struct MapLayout<K, V, S = SvmBuildHasher, const GROUP_WIDTH: usize = SBF_GROUP_WIDTH> {
+    hash_builder: S,
+    keys: Vec<K>,
+    hashes: Vec<u64>,
+    // The key in each bucket, as an index into `keys`
+    buckets: Vec<Option<usize>>,
+    // Whether each control byte is full, there are `GROUP_WIDTH` more of them
+    // than buckets and the ones at the end mirror the first buckets
+    ctrl: Vec<bool>,
+    bucket_mask: usize,
+    growth_left: usize,
+    entry: PhantomData<V>,
+}
+
+/// The `MapLayout` of a set, a `hashbrown` set is a map with `()` values.
+pub type SetLayout<K, S = SvmBuildHasher, const GROUP_WIDTH: usize = SBF_GROUP_WIDTH> =
+    MapLayout<K, (), S, GROUP_WIDTH>;
+
+impl<K, V> MapLayout<K, V> {
+    /// The layout of a map made with `new`.
+    pub fn new() -> Self {
+        Self::with_hasher(SvmBuildHasher::default())
+    }
+
+    /// The layout of a map made with `with_capacity`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, SvmBuildHasher::default())
+    }
+}
+
+impl<K, V> Default for MapLayout<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S, const GROUP_WIDTH: usize> MapLayout<K, V, S, GROUP_WIDTH> {
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher(0, hash_builder)
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let mut layout = Self {
+            hash_builder,
+            keys: Vec::new(),
+            hashes: Vec::new(),
+            buckets: Vec::new(),
+            // An empty map does not allocate, it probes a static group of
+            // empty control bytes
+            ctrl: vec![false; GROUP_WIDTH],
+            bucket_mask: 0,
+            growth_left: 0,
+            entry: PhantomData,
+        };
+
+        if capacity != 0 {
+            layout.allocate(capacity_to_buckets(
+                capacity,
+                mem::size_of::<(K, V)>(),
+                GROUP_WIDTH,
+            ));
+        }
+
+        layout
+    }
+
+    /// The number of buckets of the map.
+    pub fn buckets(&self) -> usize {
+        self.buckets.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// The keys in the order the map iterates over them.
+    pub fn iter(&self) -> impl Iterator<Item = &K> {
+        self.buckets
+            .iter()
+            .filter_map(|bucket| bucket.map(|index| &self.keys[index]))
+    }
+
+    /// Same as `reserve` on the map.
+    pub fn reserve(&mut self, additional: usize) {
+        if additional <= self.growth_left {
+            return;
+        }
+
+        // Without removals there are no deleted buckets to reclaim, so the
+        // table is always resized
+        let full_capacity = bucket_mask_to_capacity(self.bucket_mask);
+        let capacity = usize::max(self.len() + additional, full_capacity + 1);
+
+        let buckets = mem::take(&mut self.buckets);
+
+        self.allocate(capacity_to_buckets(
+            capacity,
+            mem::size_of::<(K, V)>(),
+            GROUP_WIDTH,
+        ));
+
+        for index in buckets.into_iter().flatten() {
+            let bucket = self.find_insert_bucket(self.hashes[index]);
+            self.set_bucket(bucket, index);
+        }
+    }
+
+    fn allocate(&mut self, buckets: usize) {
+        self.buckets = vec![None; buckets];
+        self.ctrl = vec![false; buckets + GROUP_WIDTH];
+        self.bucket_mask = buckets - 1;
+        // The keys that are moved over are taken out of it by `set_bucket`
+        self.growth_left = bucket_mask_to_capacity(self.bucket_mask);
+    }
+
+    // The buckets of every group in the probe sequence of `hash`, the indices
+    // are into `ctrl` so they can go past the last bucket
+    fn probe_seq(&self, hash: u64) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        let bucket_mask = self.bucket_mask;
+        let mut position = hash as usize & bucket_mask;
+        let mut stride = 0;
+
+        core::iter::from_fn(move || {
+            let group = position..position + GROUP_WIDTH;
+
+            stride += GROUP_WIDTH;
+            position = (position + stride) & bucket_mask;
+
+            Some(group)
+        })
+    }
+
+    fn find_insert_bucket(&self, hash: u64) -> usize {
+        for mut group in self.probe_seq(hash) {
+            if let Some(ctrl_index) = group.find(|&index| !self.ctrl[index]) {
+                let bucket = ctrl_index & self.bucket_mask;
+
+                // In tables smaller than a group the empty control bytes past
+                // the end can point back to a full bucket, `hashbrown` takes
+                // the first empty bucket of the table in that case
+                if self.buckets[bucket].is_some() {
+                    return (0..GROUP_WIDTH).find(|&index| !self.ctrl[index]).unwrap();
+                }
+
+                return bucket;
+            }
+        }
+
+        unreachable!()
+    }
+
+    fn set_bucket(&mut self, bucket: usize, index: usize) {
+        self.buckets[bucket] = Some(index);
+        self.ctrl[bucket] = true;
+        self.ctrl[(bucket.wrapping_sub(GROUP_WIDTH) & self.bucket_mask) + GROUP_WIDTH] = true;
+        self.growth_left -= 1;
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher, const GROUP_WIDTH: usize> MapLayout<K, V, S, GROUP_WIDTH> {
+    /// Same as `insert` on the map, returns `false` if the key was already in
+    /// it.
+    pub fn insert(&mut self, key: K) -> bool {
+        // `hashbrown` makes room for the key before it checks if it is there
+        self.reserve(1);
+
+        let hash = self.hash_builder.hash_one(&key);
+
+        if self.find(hash, &key).is_some() {
+            return false;
+        }
+
+        let bucket = self.find_insert_bucket(hash);
+
+        self.keys.push(key);
+        self.hashes.push(hash);
+        self.set_bucket(bucket, self.keys.len() - 1);
+
+        true
+    }
+
+    /// Same as `extend` on the map, which is also what `from_iter` uses for a
+    /// set. A map made with `from_iter` is `with_capacity` of the number of
+    /// keys followed by an `insert` for each one.
+    pub fn extend<I: IntoIterator<Item = K>>(&mut self, keys: I) {
+        let keys = keys.into_iter();
+
+        let additional = if self.is_empty() {
+            keys.size_hint().0
+        } else {
+            keys.size_hint().0.div_ceil(2)
+        };
+
+        self.reserve(additional);
+
+        for key in keys {
+            self.insert(key);
+        }
+    }
+
+    /// The bucket `key` is in.
+    pub fn bucket(&self, key: &K) -> Option<usize> {
+        self.find(self.hash_builder.hash_one(key), key)
+    }
+
+    fn find(&self, hash: u64, key: &K) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        for group in self.probe_seq(hash) {
+            let mut has_empty = false;
+
+            for ctrl_index in group {
+                if !self.ctrl[ctrl_index] {
+                    has_empty = true;
+                    continue;
+                }
+
+                let bucket = ctrl_index & self.bucket_mask;
+                let index = self.buckets[bucket].unwrap();
+
+                if self.hashes[index] == hash && self.keys[index] == *key {
+                    return Some(bucket);
+                }
+            }
+
+            if has_empty {
+                return None;
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+// Mirrors `hashbrown::raw::capacity_to_buckets`
+fn capacity_to_buckets(capacity: usize, entry_size: usize, group_width: usize) -> usize {
+    if capacity < 15 {
+        let min_capacity = match (group_width, entry_size) {
+            (16, 0..=1) => 14,
+            (16, 2..=3) => 7,
+            (8, 0..=1) => 7,
+            _ => 3,
+        };
+
+        return match min_capacity.max(capacity) {
+            capacity if capacity < 4 => 4,
+            capacity if capacity < 8 => 8,
+            _ => 16,
+        };
+    }
+
+    (capacity * 8 / 7).next_power_of_two()
+}
+
+// Mirrors `hashbrown::raw::bucket_mask_to_capacity`
+fn bucket_mask_to_capacity(bucket_mask: usize) -> usize {
+    if bucket_mask < 8 {
+        bucket_mask
+    } else {
+        ((bucket_mask + 1) / 8) * 7
+    }
+}
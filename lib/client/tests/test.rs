@@ -0,0 +1,115 @@
+use core::hash::{BuildHasherDefault, Hash, Hasher};
+
+use svm_hash_client::{hash_key, HashMap, HashSet, MapLayout, SetLayout};
+use svm_hasher::{PrehashedHasher, SvmBuildHasher, SvmSHA256Hasher};
+
+// `hashbrown` probes 16 buckets at a time on x86 with SSE2 and 8 otherwise, so
+// the layouts are checked against the host's `hashbrown` with its group width
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+const HOST_GROUP_WIDTH: usize = 16;
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+)))]
+const HOST_GROUP_WIDTH: usize = 8;
+
+type HostSetLayout<K, S = SvmBuildHasher> = SetLayout<K, S, HOST_GROUP_WIDTH>;
+type HostMapLayout<K, V> = MapLayout<K, V, SvmBuildHasher, HOST_GROUP_WIDTH>;
+
+fn keys(count: u64) -> Vec<[u8; 32]> {
+    (0..count)
+        .map(|index| {
+            let mut key = [0; 32];
+            key[..8].copy_from_slice(&index.wrapping_mul(0x9e37_79b9).to_le_bytes());
+            key
+        })
+        .collect()
+}
+
+#[test]
+fn test_hash_key() {
+    let mut hasher = SvmSHA256Hasher::default();
+    [7u8; 32].hash(&mut hasher);
+
+    assert_eq!(hash_key(&[7u8; 32]), hasher.finish());
+}
+
+#[test]
+fn test_insert_order() {
+    for count in [0, 1, 3, 4, 7, 8, 15, 100, 500] {
+        let keys = keys(count);
+
+        let mut set = HashSet::default();
+        let mut layout = HostSetLayout::with_hasher(SvmBuildHasher::default());
+
+        for key in &keys {
+            assert_eq!(set.insert(*key), layout.insert(*key));
+        }
+
+        assert!(set.iter().eq(layout.iter()), "{count} keys");
+    }
+}
+
+#[test]
+fn test_with_capacity_order() {
+    for (capacity, count) in [(4, 4), (4, 40), (100, 80), (16, 14)] {
+        let keys = keys(count);
+
+        let mut map = HashMap::with_capacity_and_hasher(capacity, SvmBuildHasher::default());
+        let mut layout =
+            HostMapLayout::<[u8; 32], u64>::with_capacity_and_hasher(capacity, Default::default());
+
+        for key in &keys {
+            map.insert(*key, 0u64);
+            layout.insert(*key);
+        }
+
+        assert!(map.keys().eq(layout.iter()), "{capacity} capacity");
+
+        for key in &keys {
+            assert!(layout.bucket(key).is_some());
+        }
+    }
+}
+
+#[test]
+fn test_duplicate_keys_order() {
+    let keys = keys(20);
+
+    let mut set = HashSet::default();
+    let mut layout = HostSetLayout::with_hasher(SvmBuildHasher::default());
+
+    // Inserting a key that is already there can still grow the map
+    for key in keys.iter().chain(keys.iter()).chain(keys[..5].iter()) {
+        assert_eq!(set.insert(*key), layout.insert(*key));
+        assert!(set.iter().eq(layout.iter()));
+    }
+}
+
+#[test]
+fn test_from_iter_and_extend_order() {
+    let keys = keys(50);
+
+    let set: HashSet<_> = keys[..30].iter().copied().collect();
+    let mut layout = HostSetLayout::with_hasher(SvmBuildHasher::default());
+    layout.extend(keys[..30].iter().copied());
+
+    assert!(set.iter().eq(layout.iter()));
+
+    let mut set = set;
+    set.extend(keys[30..].iter().copied());
+    layout.extend(keys[30..].iter().copied());
+
+    assert!(set.iter().eq(layout.iter()));
+
+    // Small entries get a larger minimum table
+    let set: hashbrown::HashSet<u8, BuildHasherDefault<PrehashedHasher>> = (0..5).collect();
+    let mut layout =
+        HostSetLayout::<u8, BuildHasherDefault<PrehashedHasher>>::with_hasher(Default::default());
+    layout.extend(0..5);
+
+    assert!(set.iter().eq(layout.iter()));
+}
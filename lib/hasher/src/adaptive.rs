@@ -0,0 +1,68 @@
+use core::hash::{BuildHasherDefault, Hasher};
+
+use crate::{fast::fmix64, prehashed::MULTIPLIER, Sha256, SvmBufferedHasher};
+
+/// The input length, in bytes, up to which `AdaptiveHasher` uses the mixer.
+///
+/// This value is a placeholder and has not been measured. It covers a length
+/// prefixed `Pubkey` with a couple of integer fields. `test_adaptive_threshold`
+/// in the test program measures the mixer against `sol_sha256` for every key
+/// length from 16 to 263 bytes and prints where the syscall gets cheaper. It
+/// needs an SBF build and is ignored, so it does not check this value yet.
+/// Set the constant from its output.
+pub const DEFAULT_ADAPTIVE_THRESHOLD: usize = 64;
+
+pub type AdaptiveBuildHasher<const THRESHOLD: usize = DEFAULT_ADAPTIVE_THRESHOLD> =
+    BuildHasherDefault<AdaptiveHasher<THRESHOLD>>;
+
+/// A hasher that hashes inputs of up to `THRESHOLD` bytes with a cheap 64 bit
+/// mixer and longer inputs with `sol_sha256`.
+///
+/// The writes are buffered until they go over the threshold, so the choice is
+/// made on the total length of the key rather than on each write.
+#[derive(Clone, Default)]
+pub struct AdaptiveHasher<const THRESHOLD: usize = DEFAULT_ADAPTIVE_THRESHOLD> {
+    hasher: SvmBufferedHasher<Sha256, THRESHOLD>,
+}
+
+impl<const THRESHOLD: usize> Hasher for AdaptiveHasher<THRESHOLD> {
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher.write(bytes);
+    }
+
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        // The buffered hasher only calls the syscall once its buffer overflows
+        if self.hasher.is_used {
+            self.hasher.finish()
+        } else {
+            mix(self.hasher.buffered())
+        }
+    }
+}
+
+#[inline(always)]
+fn mix(bytes: &[u8]) -> u64 {
+    let mut hash = (bytes.len() as u64).wrapping_mul(MULTIPLIER);
+
+    let mut chunks = bytes.chunks_exact(8);
+
+    for chunk in &mut chunks {
+        hash = (hash ^ u64::from_le_bytes(chunk.try_into().unwrap()))
+            .wrapping_mul(MULTIPLIER)
+            .rotate_left(31);
+    }
+
+    let remainder = chunks.remainder();
+
+    if !remainder.is_empty() {
+        let mut word = [0; 8];
+        word[..remainder.len()].copy_from_slice(remainder);
+        hash = (hash ^ u64::from_le_bytes(word))
+            .wrapping_mul(MULTIPLIER)
+            .rotate_left(31);
+    }
+
+    fmix64(hash)
+}
@@ -0,0 +1,25 @@
+use crate::{sol_blake3, SvmBuildHasherWith, SvmHasher, SyscallDigest, HASH_BYTES};
+
+/// `Blake3` through the `sol_blake3` syscall.
+pub struct Blake3;
+
+impl SyscallDigest for Blake3 {
+    #[inline(always)]
+    unsafe fn syscall(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64 {
+        unsafe { sol_blake3(vals, val_len, hash_result) }
+    }
+
+    fn fallback(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+        let mut blake3_hasher = blake3::Hasher::new();
+
+        for val in vals {
+            blake3_hasher.update(val);
+        }
+
+        *blake3_hasher.finalize().as_bytes()
+    }
+}
+
+pub type SvmBlake3BuildHasher = SvmBuildHasherWith<Blake3>;
+
+pub type SvmBlake3Hasher = SvmHasher<Blake3>;
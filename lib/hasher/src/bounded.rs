@@ -0,0 +1,108 @@
+use core::hash::{BuildHasher, Hasher};
+
+use crate::{SvmBuildHasher, SvmSHA256Hasher};
+
+/// A `BuildHasher` for `BoundedHasher`, it caps how many bytes of every write
+/// are hashed.
+#[derive(Clone)]
+pub struct BoundedBuildHasher<S = SvmBuildHasher> {
+    bound: usize,
+    hash_builder: S,
+}
+
+impl<S: Default> BoundedBuildHasher<S> {
+    pub fn new(bound: usize) -> Self {
+        Self::with_hasher(bound, S::default())
+    }
+}
+
+impl<S> BoundedBuildHasher<S> {
+    pub fn with_hasher(bound: usize, hash_builder: S) -> Self {
+        Self {
+            bound,
+            hash_builder,
+        }
+    }
+
+    pub fn bound(&self) -> usize {
+        self.bound
+    }
+}
+
+impl<S: BuildHasher> BuildHasher for BoundedBuildHasher<S> {
+    type Hasher = BoundedHasher<S::Hasher>;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        BoundedHasher {
+            hasher: self.hash_builder.build_hasher(),
+            bound: self.bound,
+        }
+    }
+}
+
+/// A hasher that only passes the length and the first and last `bound` bytes
+/// of a long write to the inner hasher, so the cost of hashing a key stops
+/// growing with its length.
+///
+/// Keys of the same length that only differ in the middle all collide. Lookups
+/// still return the right entry, since `hashbrown` compares the keys
+/// themselves, but they are not cheap: a lookup probes the whole chain of
+/// colliding keys and compares each of them in full. Whoever chooses the keys,
+/// e.g. through instruction data, can make every lookup of one of them cost a
+/// full comparison against all the others, so it is only a fit for keys that
+/// are not chosen by an attacker or for maps that stay small. Integer writes
+/// are passed through as they are.
+#[derive(Clone)]
+pub struct BoundedHasher<H = SvmSHA256Hasher> {
+    hasher: H,
+    bound: usize,
+}
+
+impl<H: Hasher> Hasher for BoundedHasher<H> {
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        if bytes.len() <= self.bound.saturating_mul(2) {
+            self.hasher.write(bytes);
+            return;
+        }
+
+        self.hasher.write_usize(bytes.len());
+        self.hasher.write(&bytes[..self.bound]);
+        self.hasher.write(&bytes[bytes.len() - self.bound..]);
+    }
+
+    #[inline(always)]
+    fn write_u8(&mut self, i: u8) {
+        self.hasher.write_u8(i);
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, i: u16) {
+        self.hasher.write_u16(i);
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, i: u32) {
+        self.hasher.write_u32(i);
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, i: u64) {
+        self.hasher.write_u64(i);
+    }
+
+    #[inline(always)]
+    fn write_u128(&mut self, i: u128) {
+        self.hasher.write_u128(i);
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, i: usize) {
+        self.hasher.write_usize(i);
+    }
+
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        self.hasher.finish()
+    }
+}
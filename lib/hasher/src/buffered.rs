@@ -0,0 +1,110 @@
+use core::{
+    hash::{BuildHasherDefault, Hasher},
+    marker::PhantomData,
+};
+
+use crate::{Hasher256, Sha256, SyscallDigest, HASH_BYTES};
+
+/// Large enough for a length prefixed `Pubkey` and a couple of integer fields,
+/// which covers most of the keys used on-chain.
+pub const DEFAULT_BUFFER_BYTES: usize = 64;
+
+pub type SvmBufferedBuildHasher<A = Sha256, const BUFFER_BYTES: usize = DEFAULT_BUFFER_BYTES> =
+    BuildHasherDefault<SvmBufferedHasher<A, BUFFER_BYTES>>;
+
+pub type SvmBufferedSHA256Hasher = SvmBufferedHasher<Sha256>;
+
+/// A hasher that collects the writes into an inline buffer and hashes them
+/// with a single syscall in `finish`.
+///
+/// Writes that do not fit in the buffer are chained into the state the same
+/// way `SvmHasher` does it, so large inputs still work. A key that overflows
+/// the buffer with its last write still costs a single syscall.
+pub struct SvmBufferedHasher<A = Sha256, const BUFFER_BYTES: usize = DEFAULT_BUFFER_BYTES> {
+    buffer: [u8; BUFFER_BYTES],
+    buffer_len: usize,
+    state: [u8; HASH_BYTES],
+    pub(crate) is_used: bool,
+    algorithm: PhantomData<fn() -> A>,
+}
+
+impl<A, const BUFFER_BYTES: usize> Clone for SvmBufferedHasher<A, BUFFER_BYTES> {
+    fn clone(&self) -> Self {
+        Self {
+            buffer: self.buffer,
+            buffer_len: self.buffer_len,
+            state: self.state,
+            is_used: self.is_used,
+            algorithm: PhantomData,
+        }
+    }
+}
+
+impl<A, const BUFFER_BYTES: usize> Default for SvmBufferedHasher<A, BUFFER_BYTES> {
+    fn default() -> Self {
+        Self {
+            buffer: [0; BUFFER_BYTES],
+            buffer_len: 0,
+            state: [0; HASH_BYTES],
+            is_used: false,
+            algorithm: PhantomData,
+        }
+    }
+}
+
+impl<A, const BUFFER_BYTES: usize> SvmBufferedHasher<A, BUFFER_BYTES> {
+    #[inline(always)]
+    pub(crate) fn buffered(&self) -> &[u8] {
+        &self.buffer[..self.buffer_len]
+    }
+
+    #[inline(always)]
+    fn previous_state(&self) -> &[u8] {
+        if self.is_used {
+            &self.state[..]
+        } else {
+            &[]
+        }
+    }
+}
+
+impl<A: SyscallDigest, const BUFFER_BYTES: usize> Hasher for SvmBufferedHasher<A, BUFFER_BYTES> {
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        let buffer_len = self.buffer_len + bytes.len();
+
+        if buffer_len <= BUFFER_BYTES {
+            self.buffer[self.buffer_len..buffer_len].copy_from_slice(bytes);
+            self.buffer_len = buffer_len;
+            return;
+        }
+
+        // The buffer overflowed, so the buffered bytes and the new bytes are
+        // folded into the state and the buffer starts over
+        self.state = A::hashv(&[
+            self.previous_state(),
+            &self.buffer[..self.buffer_len],
+            bytes,
+        ]);
+        self.is_used = true;
+        self.buffer_len = 0;
+    }
+
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        u64::from_le_bytes(self.finish_digest()[..8].try_into().unwrap())
+    }
+}
+
+impl<A: SyscallDigest, const BUFFER_BYTES: usize> Hasher256 for SvmBufferedHasher<A, BUFFER_BYTES> {
+    #[inline(always)]
+    fn finish_digest(&self) -> [u8; HASH_BYTES] {
+        match (self.is_used, self.buffer_len != 0) {
+            // Nothing was written since the last overflow, its state is
+            // already the digest
+            (true, false) => self.state,
+            (true, true) => A::hashv(&[&self.state[..], &self.buffer[..self.buffer_len]]),
+            (false, _) => A::hashv(&[&self.buffer[..self.buffer_len]]),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use core::hash::{BuildHasherDefault, Hasher};
+
+use sha2::Digest;
+
+use crate::{Hasher256, Sha256, SyscallDigest, HASH_BYTES};
+
+/// Keys of up to this many bytes are hashed with a single `sol_sha256` call.
+pub const DEFAULT_CANONICAL_BUFFER_BYTES: usize = 128;
+
+pub type SvmCanonicalBuildHasher<const BUFFER_BYTES: usize = DEFAULT_CANONICAL_BUFFER_BYTES> =
+    BuildHasherDefault<SvmCanonicalHasher<BUFFER_BYTES>>;
+
+/// A hasher whose `finish_digest` is the `SHA256` of everything that was
+/// written, so the hashes can be reproduced off-chain with any `SHA256`
+/// library.
+///
+/// `sol_sha256` can not be continued across calls, so the writes are buffered
+/// and hashed with a single call in `finish`. Keys that do not fit in the
+/// buffer are hashed in the program with `sha2` instead, which gives the same
+/// digest for a higher cost.
+#[derive(Clone)]
+pub struct SvmCanonicalHasher<const BUFFER_BYTES: usize = DEFAULT_CANONICAL_BUFFER_BYTES> {
+    buffer: [u8; BUFFER_BYTES],
+    buffer_len: usize,
+    overflow: Option<sha2::Sha256>,
+}
+
+impl<const BUFFER_BYTES: usize> Default for SvmCanonicalHasher<BUFFER_BYTES> {
+    fn default() -> Self {
+        Self {
+            buffer: [0; BUFFER_BYTES],
+            buffer_len: 0,
+            overflow: None,
+        }
+    }
+}
+
+impl<const BUFFER_BYTES: usize> Hasher for SvmCanonicalHasher<BUFFER_BYTES> {
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        if let Some(sha256_hasher) = &mut self.overflow {
+            sha256_hasher.update(bytes);
+            return;
+        }
+
+        let buffer_len = self.buffer_len + bytes.len();
+
+        if buffer_len <= BUFFER_BYTES {
+            self.buffer[self.buffer_len..buffer_len].copy_from_slice(bytes);
+            self.buffer_len = buffer_len;
+            return;
+        }
+
+        let mut sha256_hasher = sha2::Sha256::new();
+        sha256_hasher.update(&self.buffer[..self.buffer_len]);
+        sha256_hasher.update(bytes);
+
+        self.overflow = Some(sha256_hasher);
+    }
+
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        u64::from_le_bytes(self.finish_digest()[..8].try_into().unwrap())
+    }
+}
+
+impl<const BUFFER_BYTES: usize> Hasher256 for SvmCanonicalHasher<BUFFER_BYTES> {
+    #[inline(always)]
+    fn finish_digest(&self) -> [u8; HASH_BYTES] {
+        match &self.overflow {
+            Some(sha256_hasher) => sha256_hasher.clone().finalize().into(),
+            None => Sha256::hashv(&[&self.buffer[..self.buffer_len]]),
+        }
+    }
+}
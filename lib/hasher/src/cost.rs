@@ -0,0 +1,173 @@
+//! Predicts the compute units that `SvmSHA256Hasher` spends on a key, so
+//! compute budgets can be sized without running the benchmarks.
+//!
+//! The syscall costs follow the runtime's `sol_sha256` formula, which
+//! `sol_keccak256` and `sol_blake3` are charged with as well. The table work of
+//! a map is not a syscall, so it is a rough constant that has not been
+//! measured yet.
+
+use core::hash::{Hash, Hasher};
+
+use crate::{HASH_BYTES, PENDING_BYTES};
+
+/// Charged once for every `sol_sha256` call.
+pub const SHA256_BASE_COST: u64 = 85;
+
+/// Charged for every 2 bytes of a slice.
+pub const SHA256_BYTE_COST: u64 = 1;
+
+/// The least a slice is charged, even an empty one.
+pub const MEM_OP_BASE_COST: u64 = 10;
+
+/// A rough estimate of the probing and key comparison of a map lookup, on top
+/// of hashing the key. It has not been measured yet. `test_map_costs` in the
+/// test program measures lookups of byte keys from 8 to 255 bytes, so set it
+/// from that output.
+pub const LOOKUP_OVERHEAD: u64 = 100;
+
+/// A rough estimate of the table work of a map insert that does not grow the
+/// table, on top of hashing the key. Like `LOOKUP_OVERHEAD`, it has not been
+/// measured yet.
+pub const INSERT_OVERHEAD: u64 = 150;
+
+/// The cost of a single `sol_sha256` call over slices of `slice_lens` bytes.
+pub const fn syscall_cost(slice_lens: &[usize]) -> u64 {
+    let mut cost = SHA256_BASE_COST;
+    let mut i = 0;
+
+    while i < slice_lens.len() {
+        let byte_cost = SHA256_BYTE_COST * (slice_lens[i] as u64 / 2);
+
+        cost += if byte_cost > MEM_OP_BASE_COST {
+            byte_cost
+        } else {
+            MEM_OP_BASE_COST
+        };
+        i += 1;
+    }
+
+    cost
+}
+
+/// The cost of a single `write` of `len` bytes followed by `finish`.
+pub fn write_cost(len: usize) -> u64 {
+    let mut hasher = CostHasher::default();
+    hasher.flush(len);
+    hasher.finish_cost()
+}
+
+/// The cost of hashing a `[u8]` or `[u8; N]` of `len` bytes, which writes its
+/// length before the bytes.
+pub fn slice_cost(len: usize) -> u64 {
+    let mut hasher = CostHasher::default();
+    hasher.write_usize(len);
+    hasher.flush(len);
+    hasher.finish_cost()
+}
+
+/// The cost of hashing `key` with `SvmSHA256Hasher`.
+pub fn key_cost<K: Hash + ?Sized>(key: &K) -> u64 {
+    let mut hasher = CostHasher::default();
+    key.hash(&mut hasher);
+    hasher.finish_cost()
+}
+
+/// An estimate of a lookup of `key` in a map that uses `SvmBuildHasher`.
+pub fn map_lookup_cost<K: Hash + ?Sized>(key: &K) -> u64 {
+    key_cost(key) + LOOKUP_OVERHEAD
+}
+
+/// An estimate of an insert of `key` in a map that uses `SvmBuildHasher`.
+///
+/// When the insert grows the table every key in it is hashed again, which is
+/// not included.
+pub fn map_insert_cost<K: Hash + ?Sized>(key: &K) -> u64 {
+    key_cost(key) + INSERT_OVERHEAD
+}
+
+// Goes through the same states as `SvmHasher`, but adds up the cost of the
+// syscalls instead of making them
+#[derive(Default)]
+struct CostHasher {
+    is_used: bool,
+    pending_len: usize,
+    cost: u64,
+}
+
+impl CostHasher {
+    fn write_pending(&mut self, len: usize) {
+        if self.pending_len + len > PENDING_BYTES {
+            self.flush(0);
+        }
+
+        self.pending_len += len;
+    }
+
+    fn flush(&mut self, len: usize) {
+        let mut slice_lens = [0; 3];
+        let mut slice_count = 0;
+
+        if self.is_used {
+            slice_lens[slice_count] = HASH_BYTES;
+            slice_count += 1;
+        }
+
+        if self.pending_len != 0 {
+            slice_lens[slice_count] = self.pending_len;
+            slice_count += 1;
+        }
+
+        if len != 0 {
+            slice_lens[slice_count] = len;
+            slice_count += 1;
+        }
+
+        self.cost += syscall_cost(&slice_lens[..slice_count]);
+        self.is_used = true;
+        self.pending_len = 0;
+    }
+
+    fn finish_cost(&self) -> u64 {
+        self.cost
+            + match (self.is_used, self.pending_len != 0) {
+                (true, false) => 0,
+                (true, true) => syscall_cost(&[HASH_BYTES, self.pending_len]),
+                (false, true) => syscall_cost(&[self.pending_len]),
+                (false, false) => syscall_cost(&[]),
+            }
+    }
+}
+
+impl Hasher for CostHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.flush(bytes.len());
+    }
+
+    fn write_u8(&mut self, _: u8) {
+        self.write_pending(1);
+    }
+
+    fn write_u16(&mut self, _: u16) {
+        self.write_pending(2);
+    }
+
+    fn write_u32(&mut self, _: u32) {
+        self.write_pending(4);
+    }
+
+    fn write_u64(&mut self, _: u64) {
+        self.write_pending(8);
+    }
+
+    fn write_u128(&mut self, _: u128) {
+        self.write_pending(16);
+    }
+
+    fn write_usize(&mut self, _: usize) {
+        self.write_pending(core::mem::size_of::<usize>());
+    }
+
+    fn finish(&self) -> u64 {
+        self.finish_cost()
+    }
+}
@@ -0,0 +1,99 @@
+use core::hash::{BuildHasherDefault, Hasher};
+
+use crate::prehashed::MULTIPLIER;
+
+// The fractional digits of pi, any odd constant works, it only keeps the state
+// away from zero
+const SEED: u64 = 0x243f_6a88_85a3_08d3;
+
+pub type SvmFastBuildHasher = BuildHasherDefault<SvmFastHasher>;
+
+/// A non-cryptographic hasher that makes no syscalls, for keys that are not
+/// chosen by an attacker.
+///
+/// It folds every 8 bytes into the state with a single 64 bit multiply and a
+/// rotate. The folded 128 bit multiply of `wyhash` and `foldhash` is left out,
+/// since sBPF has no widening multiply and it would be a call into
+/// `compiler_builtins`. There is no random state, so the hashes are the same
+/// in every program and every run.
+#[derive(Clone)]
+pub struct SvmFastHasher {
+    hash: u64,
+}
+
+impl Default for SvmFastHasher {
+    fn default() -> Self {
+        Self { hash: SEED }
+    }
+}
+
+impl SvmFastHasher {
+    #[inline(always)]
+    fn fold(&mut self, word: u64) {
+        self.hash = (self.hash ^ word).wrapping_mul(MULTIPLIER).rotate_left(29);
+    }
+}
+
+impl Hasher for SvmFastHasher {
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+
+        for chunk in &mut chunks {
+            self.fold(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+
+        let remainder = chunks.remainder();
+
+        if !remainder.is_empty() {
+            let mut word = [0; 8];
+            word[..remainder.len()].copy_from_slice(remainder);
+            self.fold(u64::from_le_bytes(word));
+        }
+
+        // Keeps the zero padding from colliding with written zeros, and a
+        // write from colliding with the same bytes split over two writes
+        self.fold(bytes.len() as u64);
+    }
+
+    #[inline(always)]
+    fn write_u8(&mut self, i: u8) {
+        self.fold(u64::from(i));
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, i: u16) {
+        self.fold(u64::from(i));
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, i: u32) {
+        self.fold(u64::from(i));
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, i: u64) {
+        self.fold(i);
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, i: usize) {
+        self.fold(i as u64);
+    }
+
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        fmix64(self.hash)
+    }
+}
+
+// The finalizer from murmur3, so every input bit affects every output bit,
+// which the probing of `hashbrown` relies on
+#[inline(always)]
+pub(crate) fn fmix64(mut hash: u64) -> u64 {
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^ (hash >> 33)
+}
@@ -0,0 +1,62 @@
+use core::{
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+/// A key that always has the same length, so it can be hashed without the
+/// length prefix that the `Hash` impls of slices and arrays write.
+pub trait FixedKey {
+    /// Writes the key with a single `write`.
+    fn write_fixed<H: Hasher>(&self, state: &mut H);
+}
+
+impl<const N: usize> FixedKey for [u8; N] {
+    #[inline(always)]
+    fn write_fixed<H: Hasher>(&self, state: &mut H) {
+        state.write(self);
+    }
+}
+
+impl FixedKey for u64 {
+    #[inline(always)]
+    fn write_fixed<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.to_le_bytes());
+    }
+}
+
+impl FixedKey for u128 {
+    #[inline(always)]
+    fn write_fixed<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.to_le_bytes());
+    }
+}
+
+/// A key wrapper that is hashed through `FixedKey`.
+///
+/// With `SvmSHA256Hasher` a `Fixed<Pubkey>` is hashed with a single 32 byte
+/// slice, instead of the length prefix and the 32 bytes, which saves the
+/// minimum charge of the extra slice on every lookup. A `Fixed<K>` hashes
+/// differently from a `K`, so lookups have to use the wrapper as well.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Fixed<K>(pub K);
+
+impl<K: FixedKey> Hash for Fixed<K> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.write_fixed(state);
+    }
+}
+
+impl<K> Deref for Fixed<K> {
+    type Target = K;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K> From<K> for Fixed<K> {
+    fn from(value: K) -> Self {
+        Self(value)
+    }
+}
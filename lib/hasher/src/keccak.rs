@@ -0,0 +1,27 @@
+use sha3::Digest;
+
+use crate::{sol_keccak256, SvmBuildHasherWith, SvmHasher, SyscallDigest, HASH_BYTES};
+
+/// `Keccak-256` through the `sol_keccak256` syscall.
+pub struct Keccak256;
+
+impl SyscallDigest for Keccak256 {
+    #[inline(always)]
+    unsafe fn syscall(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64 {
+        unsafe { sol_keccak256(vals, val_len, hash_result) }
+    }
+
+    fn fallback(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+        let mut keccak_hasher = sha3::Keccak256::new();
+
+        for val in vals {
+            keccak_hasher.update(val);
+        }
+
+        keccak_hasher.finalize().into()
+    }
+}
+
+pub type SvmKeccakBuildHasher = SvmBuildHasherWith<Keccak256>;
+
+pub type SvmKeccakHasher = SvmHasher<Keccak256>;
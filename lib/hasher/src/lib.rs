@@ -1,91 +1,332 @@
 #![no_std]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::{
     hash::{BuildHasherDefault, Hasher},
-    mem::MaybeUninit,
+    marker::PhantomData,
 };
 
-#[cfg(not(target_os = "solana"))]
-use sha2::{Digest, Sha256};
+use sha2::Digest;
 
 #[cfg(target_os = "solana")]
 unsafe extern "C" {
     fn sol_sha256(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64;
+    fn sol_keccak256(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64;
+    #[cfg(feature = "blake3")]
+    fn sol_blake3(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64;
+    #[cfg(feature = "poseidon")]
+    fn sol_poseidon(
+        parameters: u64,
+        endianness: u64,
+        vals: *const u8,
+        val_len: u64,
+        hash_result: *mut u8,
+    ) -> u64;
 }
 
+mod adaptive;
+#[cfg(feature = "blake3")]
+mod blake;
+mod bounded;
+mod buffered;
+mod canonical;
+pub mod cost;
+mod fast;
+mod fixed;
+mod keccak;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "poseidon")]
+mod poseidon;
+mod prehashed;
+mod seeded;
+#[cfg(feature = "alloc")]
+mod sha256;
+#[cfg(not(target_os = "solana"))]
+pub mod shim;
+#[cfg(feature = "software-sha256")]
+mod software;
+mod vectored;
+
+pub use adaptive::{AdaptiveBuildHasher, AdaptiveHasher, DEFAULT_ADAPTIVE_THRESHOLD};
+#[cfg(feature = "blake3")]
+pub use blake::{Blake3, SvmBlake3BuildHasher, SvmBlake3Hasher};
+pub use bounded::{BoundedBuildHasher, BoundedHasher};
+pub use buffered::{
+    SvmBufferedBuildHasher, SvmBufferedHasher, SvmBufferedSHA256Hasher, DEFAULT_BUFFER_BYTES,
+};
+pub use canonical::{SvmCanonicalBuildHasher, SvmCanonicalHasher, DEFAULT_CANONICAL_BUFFER_BYTES};
+pub use fast::{SvmFastBuildHasher, SvmFastHasher};
+pub use fixed::{Fixed, FixedKey};
+pub use keccak::{Keccak256, SvmKeccakBuildHasher, SvmKeccakHasher};
+#[cfg(feature = "metrics")]
+pub use metrics::{key_metrics, MeteredBuildHasher, MeteredHasher, Metrics};
+#[cfg(feature = "poseidon")]
+pub use poseidon::{SvmPoseidonBuildHasher, SvmPoseidonHasher};
+pub use prehashed::{PrehashedBuildHasher, PrehashedHasher};
+pub use seeded::SeededSvmBuildHasher;
+#[cfg(feature = "alloc")]
+pub use sha256::SvmSha256;
+#[cfg(feature = "software-sha256")]
+pub use software::{SoftwareSha256, SvmSoftwareBuildHasher, SvmSoftwareSHA256Hasher};
+pub use vectored::{hashv, hashv_with, HashSlices, MAX_SLICES};
+
+// The syscalls that `SyscallDigest::syscall` calls off-chain
+#[cfg(not(target_os = "solana"))]
+use shim::*;
+
 const HASH_BYTES: usize = 32;
 
+/// A 32 byte digest that is provided by a syscall which takes an array of
+/// slices, like `sol_sha256`, with a software fallback for off-chain use.
+///
+/// Implementing it is enough to get a `SvmHasher` for the algorithm. Both
+/// `syscall` and `fallback` are needed in every build, `hashv` picks one of
+/// them, so an implementation does not need any `cfg`s and keeps compiling
+/// whichever features are enabled.
+pub trait SyscallDigest {
+    /// The syscall, it hashes the `val_len` slices at `vals` into `hash_result`.
+    ///
+    /// Off-chain it can call the functions in `shim`, which `hashv` uses
+    /// instead of `fallback` with the `syscall-shim` feature.
+    ///
+    /// # Safety
+    ///
+    /// `vals` has to point to `val_len` slices, and `hash_result` to 32
+    /// writable bytes.
+    unsafe fn syscall(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64;
+
+    /// Computes the same digest as `syscall` does for `vals`.
+    fn fallback(vals: &[&[u8]]) -> [u8; HASH_BYTES];
+
+    /// Hashes all the `vals` as one input with a single syscall on-chain, and
+    /// with `fallback` off-chain.
+    #[inline(always)]
+    fn hashv(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+        #[cfg(any(target_os = "solana", feature = "syscall-shim"))]
+        {
+            let mut hash_result = [0u8; HASH_BYTES];
+
+            unsafe {
+                Self::syscall(
+                    vals as *const _ as *const u8,
+                    vals.len() as u64,
+                    hash_result.as_mut_ptr(),
+                );
+            }
+
+            hash_result
+        }
+
+        #[cfg(not(any(target_os = "solana", feature = "syscall-shim")))]
+        {
+            Self::fallback(vals)
+        }
+    }
+}
+
+/// A `Hasher` that can return the whole 32 byte digest of what was written,
+/// not just the `u64` from `finish`.
+///
+/// This lets the same pass over a key feed a map lookup and anything that
+/// needs the full digest, like a commitment or a Merkle leaf.
+pub trait Hasher256: Hasher {
+    fn finish_digest(&self) -> [u8; HASH_BYTES];
+}
+
+/// `SHA256` through the `sol_sha256` syscall.
+pub struct Sha256;
+
+impl SyscallDigest for Sha256 {
+    #[inline(always)]
+    unsafe fn syscall(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64 {
+        unsafe { sol_sha256(vals, val_len, hash_result) }
+    }
+
+    fn fallback(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+        let mut sha256_hasher = sha2::Sha256::new();
+
+        for val in vals {
+            sha256_hasher.update(val);
+        }
+
+        sha256_hasher.finalize().into()
+    }
+}
+
 pub type SvmBuildHasher = BuildHasherDefault<SvmSHA256Hasher>;
 
-pub struct SvmSHA256Hasher {
-    state: MaybeUninit<[u8; HASH_BYTES]>,
-    is_used: bool, 
+/// `SvmBuildHasher` with any `SyscallDigest`.
+pub type SvmBuildHasherWith<A> = BuildHasherDefault<SvmHasher<A>>;
+
+pub type SvmSHA256Hasher = SvmHasher<Sha256>;
+
+// Enough for a few integers and a length prefix, which is what tuple and
+// struct keys usually write between their byte writes
+const PENDING_BYTES: usize = 32;
+
+pub struct SvmHasher<A = Sha256> {
+    state: [u8; HASH_BYTES],
+    is_used: bool,
+    pending: [u8; PENDING_BYTES],
+    pending_len: usize,
+    #[cfg(feature = "metrics")]
+    metrics: Metrics,
+    algorithm: PhantomData<fn() -> A>,
+}
+
+impl<A> Clone for SvmHasher<A> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state,
+            is_used: self.is_used,
+            pending: self.pending,
+            pending_len: self.pending_len,
+            #[cfg(feature = "metrics")]
+            metrics: self.metrics,
+            algorithm: PhantomData,
+        }
+    }
 }
 
-impl Default for SvmSHA256Hasher {
+impl<A> Default for SvmHasher<A> {
     fn default() -> Self {
+        Self::with_state([0; HASH_BYTES], false)
+    }
+}
+
+impl<A> SvmHasher<A> {
+    // With `is_used` set, the hasher starts as if `state` was the result of a
+    // previous write, so it gets chained into the first write
+    #[inline(always)]
+    pub(crate) fn with_state(state: [u8; HASH_BYTES], is_used: bool) -> Self {
         Self {
-            state: MaybeUninit::uninit(),
-            is_used: false,
+            state,
+            is_used,
+            pending: [0; PENDING_BYTES],
+            pending_len: 0,
+            #[cfg(feature = "metrics")]
+            metrics: Metrics::default(),
+            algorithm: PhantomData,
         }
     }
+
+    /// The syscalls this hasher made so far and the one `finish` makes.
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> Metrics {
+        let mut metrics = self.metrics;
+
+        match (self.is_used, self.pending_len != 0) {
+            (true, false) => {}
+            (true, true) => metrics.record(&[&self.state[..], &self.pending[..self.pending_len]]),
+            (false, true) => metrics.record(&[&self.pending[..self.pending_len]]),
+            (false, false) => metrics.record(&[]),
+        }
+
+        metrics
+    }
 }
 
-impl Hasher for SvmSHA256Hasher {
+impl<A: SyscallDigest> SvmHasher<A> {
+    // Integers are only copied into `pending`, they are hashed with the next
+    // byte write or in `finish`, so a key that writes a few integers around
+    // its byte writes does not pay a syscall for each of them
     #[inline(always)]
-    #[cfg(target_os = "solana")]
-    fn write(&mut self, bytes: &[u8]) {
-        let state = self.state;
-
-        let data = &[
-            if self.is_used {
-                unsafe { &state.assume_init_ref()[..] }
-            } else {
-                self.is_used = true;
-                &[]
-            },
-            bytes,
-        ][..];
-
-        unsafe {
-            sol_sha256(
-                data as *const _ as *const u8,
-                data.len() as u64,
-                self.state.assume_init_mut() as *mut _ as *mut u8,
-            );
+    fn write_pending(&mut self, bytes: &[u8]) {
+        if self.pending_len + bytes.len() > PENDING_BYTES {
+            self.flush(&[]);
         }
+
+        self.pending[self.pending_len..self.pending_len + bytes.len()].copy_from_slice(bytes);
+        self.pending_len += bytes.len();
     }
 
-    #[cfg(not(target_os = "solana"))]
-    fn write(&mut self, bytes: &[u8]) {
-        let state = self.state;
-
-        let data = &[
-            if self.is_used {
-                unsafe { &state.assume_init_ref()[..] }
-            } else {
-                self.is_used = true;
-                &[]
-            },
-            bytes,
-        ][..];
-
-        let mut sha256_hasher = Sha256::new();
-
-        for data in data {
-            sha256_hasher.update(*data);
+    // Chains the previous state, the pending integers and `bytes` into the
+    // state with a single syscall, empty slices are left out since each slice
+    // is charged for
+    #[inline(always)]
+    fn flush(&mut self, bytes: &[u8]) {
+        let mut data: [&[u8]; 3] = [&[]; 3];
+        let mut data_len = 0;
+
+        if self.is_used {
+            data[data_len] = &self.state[..];
+            data_len += 1;
+        }
+
+        if self.pending_len != 0 {
+            data[data_len] = &self.pending[..self.pending_len];
+            data_len += 1;
+        }
+
+        if !bytes.is_empty() {
+            data[data_len] = bytes;
+            data_len += 1;
         }
 
-        unsafe { sha256_hasher.finalize_into((self.state.assume_init_mut()).into()) }
+        #[cfg(feature = "metrics")]
+        self.metrics.record(&data[..data_len]);
+
+        self.state = A::hashv(&data[..data_len]);
+        self.is_used = true;
+        self.pending_len = 0;
+    }
+}
+
+impl<A: SyscallDigest> Hasher for SvmHasher<A> {
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        self.flush(bytes);
+    }
+
+    #[inline(always)]
+    fn write_u8(&mut self, i: u8) {
+        self.write_pending(&i.to_ne_bytes());
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, i: u16) {
+        self.write_pending(&i.to_ne_bytes());
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, i: u32) {
+        self.write_pending(&i.to_ne_bytes());
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, i: u64) {
+        self.write_pending(&i.to_ne_bytes());
+    }
+
+    #[inline(always)]
+    fn write_u128(&mut self, i: u128) {
+        self.write_pending(&i.to_ne_bytes());
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, i: usize) {
+        self.write_pending(&i.to_ne_bytes());
     }
 
     #[inline(always)]
     fn finish(&self) -> u64 {
-        u64::from_le_bytes(
-            // Wherever the `Hasher` trait is used,`finish` is only
-            // ever called after write has been called at least once
-            unsafe { self.state.assume_init_ref() }[..8]
-                .try_into()
-                .unwrap(),
-        )
+        u64::from_le_bytes(self.finish_digest()[..8].try_into().unwrap())
+    }
+}
+
+impl<A: SyscallDigest> Hasher256 for SvmHasher<A> {
+    #[inline(always)]
+    fn finish_digest(&self) -> [u8; HASH_BYTES] {
+        match (self.is_used, self.pending_len != 0) {
+            (true, false) => self.state,
+            (true, true) => A::hashv(&[&self.state[..], &self.pending[..self.pending_len]]),
+            (false, true) => A::hashv(&[&self.pending[..self.pending_len]]),
+            // Keys like `()` do not write anything, they get the digest of the
+            // empty input, which is the `SHA256` of the empty string for
+            // `SvmSHA256Hasher`
+            (false, false) => A::hashv(&[]),
+        }
     }
 }
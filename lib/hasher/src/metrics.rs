@@ -0,0 +1,127 @@
+use core::{
+    cell::Cell,
+    hash::{BuildHasher, Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::{Sha256, SvmHasher, SvmSHA256Hasher, SyscallDigest};
+
+/// The syscalls a `SvmHasher` made and the bytes it passed to them, the state
+/// that is chained into a syscall is counted as 32 bytes.
+///
+/// `SvmBuildHasher` builds a new hasher for every key, so the metrics of a
+/// single key are what a map pays for every lookup or insert of it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Metrics {
+    pub syscalls: u64,
+    pub bytes: u64,
+}
+
+impl Metrics {
+    #[inline(always)]
+    pub(crate) fn record(&mut self, vals: &[&[u8]]) {
+        self.syscalls += 1;
+        self.bytes += vals.iter().map(|val| val.len() as u64).sum::<u64>();
+    }
+}
+
+/// The metrics of hashing `key` with `SvmSHA256Hasher`, including `finish`.
+pub fn key_metrics<K: Hash + ?Sized>(key: &K) -> Metrics {
+    let mut hasher = SvmSHA256Hasher::default();
+    key.hash(&mut hasher);
+    hasher.metrics()
+}
+
+/// A `BuildHasher` for `SvmHasher` that adds the metrics of every key it
+/// hashes to a shared counter, so the syscalls a whole map spent can be read
+/// after its inserts and lookups.
+///
+/// A map that grows hashes its keys again, which is counted as well.
+pub struct MeteredBuildHasher<'a, A = Sha256> {
+    metrics: &'a Cell<Metrics>,
+    algorithm: PhantomData<fn() -> A>,
+}
+
+impl<'a, A> MeteredBuildHasher<'a, A> {
+    pub fn new(metrics: &'a Cell<Metrics>) -> Self {
+        Self {
+            metrics,
+            algorithm: PhantomData,
+        }
+    }
+}
+
+impl<A> Clone for MeteredBuildHasher<'_, A> {
+    fn clone(&self) -> Self {
+        Self::new(self.metrics)
+    }
+}
+
+impl<'a, A: SyscallDigest> BuildHasher for MeteredBuildHasher<'a, A> {
+    type Hasher = MeteredHasher<'a, A>;
+
+    #[inline(always)]
+    fn build_hasher(&self) -> Self::Hasher {
+        MeteredHasher {
+            hasher: SvmHasher::default(),
+            metrics: self.metrics,
+        }
+    }
+}
+
+/// The `Hasher` of `MeteredBuildHasher`, `finish` adds the metrics of the key
+/// to the counter.
+pub struct MeteredHasher<'a, A = Sha256> {
+    hasher: SvmHasher<A>,
+    metrics: &'a Cell<Metrics>,
+}
+
+impl<A: SyscallDigest> Hasher for MeteredHasher<'_, A> {
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        self.hasher.write(bytes);
+    }
+
+    #[inline(always)]
+    fn write_u8(&mut self, i: u8) {
+        self.hasher.write_u8(i);
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, i: u16) {
+        self.hasher.write_u16(i);
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, i: u32) {
+        self.hasher.write_u32(i);
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, i: u64) {
+        self.hasher.write_u64(i);
+    }
+
+    #[inline(always)]
+    fn write_u128(&mut self, i: u128) {
+        self.hasher.write_u128(i);
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, i: usize) {
+        self.hasher.write_usize(i);
+    }
+
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        let key_metrics = self.hasher.metrics();
+        let mut metrics = self.metrics.get();
+
+        metrics.syscalls += key_metrics.syscalls;
+        metrics.bytes += key_metrics.bytes;
+
+        self.metrics.set(metrics);
+
+        self.hasher.finish()
+    }
+}
@@ -0,0 +1,160 @@
+use core::hash::{BuildHasherDefault, Hasher};
+
+#[cfg(not(any(target_os = "solana", feature = "syscall-shim")))]
+use ark_bn254::Fr;
+#[cfg(not(any(target_os = "solana", feature = "syscall-shim")))]
+use light_poseidon::{Poseidon, PoseidonBytesHasher};
+
+#[cfg(any(target_os = "solana", feature = "syscall-shim"))]
+use crate::sol_poseidon;
+
+use crate::{Hasher256, HASH_BYTES};
+
+// The most inputs `sol_poseidon` takes in a single call
+const MAX_INPUTS: usize = 12;
+
+// Any 31 byte big-endian value is below the BN254 modulus, so every chunk of
+// this size is a valid field element
+const CHUNK_BYTES: usize = HASH_BYTES - 1;
+
+#[cfg(any(target_os = "solana", feature = "syscall-shim"))]
+const BN254_X5: u64 = 0;
+#[cfg(any(target_os = "solana", feature = "syscall-shim"))]
+const BIG_ENDIAN: u64 = 0;
+
+pub type SvmPoseidonBuildHasher = BuildHasherDefault<SvmPoseidonHasher>;
+
+/// A hasher based on the `sol_poseidon` syscall with the BN254 x5 parameters,
+/// the same parameters `light-poseidon` uses for circom.
+///
+/// The written bytes are padded with a `0x01` byte and then zeros up to a
+/// multiple of 31 bytes, and each 31 byte chunk is read as a big-endian field
+/// element. The elements are hashed 12 at a time, and the digest of every call
+/// takes the first input of the next one. `finish_digest` returns the final
+/// field element in big-endian, so it can be recomputed in a proof.
+#[derive(Clone)]
+pub struct SvmPoseidonHasher {
+    elements: [[u8; HASH_BYTES]; MAX_INPUTS],
+    element_count: usize,
+    chunk: [u8; CHUNK_BYTES],
+    chunk_len: usize,
+}
+
+impl Default for SvmPoseidonHasher {
+    fn default() -> Self {
+        Self {
+            elements: [[0; HASH_BYTES]; MAX_INPUTS],
+            element_count: 0,
+            chunk: [0; CHUNK_BYTES],
+            chunk_len: 0,
+        }
+    }
+}
+
+impl Hasher for SvmPoseidonHasher {
+    fn write(&mut self, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            let len = bytes.len().min(CHUNK_BYTES - self.chunk_len);
+
+            self.chunk[self.chunk_len..self.chunk_len + len].copy_from_slice(&bytes[..len]);
+            self.chunk_len += len;
+            bytes = &bytes[len..];
+
+            // A full chunk is only pushed once it is complete, so the chunk
+            // always has room for the padding byte in `finish_digest`
+            if self.chunk_len == CHUNK_BYTES {
+                push_element(
+                    &mut self.elements,
+                    &mut self.element_count,
+                    to_element(&self.chunk),
+                );
+                self.chunk_len = 0;
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        // The digest is big-endian and below the modulus, so the low bytes
+        // are the ones that are uniformly distributed
+        u64::from_le_bytes(self.finish_digest()[HASH_BYTES - 8..].try_into().unwrap())
+    }
+}
+
+impl Hasher256 for SvmPoseidonHasher {
+    fn finish_digest(&self) -> [u8; HASH_BYTES] {
+        let mut elements = self.elements;
+        let mut element_count = self.element_count;
+
+        let mut chunk = self.chunk;
+        chunk[self.chunk_len] = 1;
+        chunk[self.chunk_len + 1..].fill(0);
+
+        push_element(&mut elements, &mut element_count, to_element(&chunk));
+
+        poseidon(&elements[..element_count])
+    }
+}
+
+#[inline(always)]
+fn to_element(chunk: &[u8; CHUNK_BYTES]) -> [u8; HASH_BYTES] {
+    let mut element = [0; HASH_BYTES];
+    element[1..].copy_from_slice(chunk);
+    element
+}
+
+fn push_element(
+    elements: &mut [[u8; HASH_BYTES]; MAX_INPUTS],
+    element_count: &mut usize,
+    element: [u8; HASH_BYTES],
+) {
+    if *element_count == MAX_INPUTS {
+        elements[0] = poseidon(&elements[..]);
+        *element_count = 1;
+    }
+
+    elements[*element_count] = element;
+    *element_count += 1;
+}
+
+#[cfg(any(target_os = "solana", feature = "syscall-shim"))]
+fn poseidon(elements: &[[u8; HASH_BYTES]]) -> [u8; HASH_BYTES] {
+    let mut vals = [&[][..]; MAX_INPUTS];
+
+    for (val, element) in vals.iter_mut().zip(elements) {
+        *val = &element[..];
+    }
+
+    let vals = &vals[..elements.len()];
+
+    let mut hash_result = [0; HASH_BYTES];
+
+    // Every element is below the modulus and there are at most `MAX_INPUTS`
+    // of them, so the syscall does not fail
+    unsafe {
+        sol_poseidon(
+            BN254_X5,
+            BIG_ENDIAN,
+            vals as *const _ as *const u8,
+            vals.len() as u64,
+            hash_result.as_mut_ptr(),
+        );
+    }
+
+    hash_result
+}
+
+#[cfg(not(any(target_os = "solana", feature = "syscall-shim")))]
+fn poseidon(elements: &[[u8; HASH_BYTES]]) -> [u8; HASH_BYTES] {
+    let mut vals = [&[][..]; MAX_INPUTS];
+
+    for (val, element) in vals.iter_mut().zip(elements) {
+        *val = &element[..];
+    }
+
+    let mut poseidon_hasher = Poseidon::<Fr>::new_circom(elements.len()).unwrap();
+
+    poseidon_hasher
+        .hash_bytes_be(&vals[..elements.len()])
+        .unwrap()
+}
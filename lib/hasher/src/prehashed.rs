@@ -0,0 +1,75 @@
+use core::hash::{BuildHasherDefault, Hasher};
+
+// 2^64 divided by the golden ratio, multiplying by it spreads the bits of a
+// small integer over the whole `u64`
+pub(crate) const MULTIPLIER: u64 = 0x9e37_79b9_7f4a_7c15;
+
+pub type PrehashedBuildHasher = BuildHasherDefault<PrehashedHasher>;
+
+/// A hasher for keys that are already uniformly distributed, like `Pubkey`s,
+/// signatures or stored digests, it folds the written bytes into a `u64`
+/// without making any syscall.
+///
+/// Integer writes, like the length prefix of a slice, are not uniformly
+/// distributed so they are multiplied before being folded in, otherwise a key
+/// that only writes a length prefix would hash to a small number.
+#[derive(Clone, Default)]
+pub struct PrehashedHasher {
+    hash: u64,
+}
+
+impl PrehashedHasher {
+    #[inline(always)]
+    fn fold(&mut self, word: u64) {
+        self.hash = self.hash.rotate_left(23) ^ word;
+    }
+}
+
+impl Hasher for PrehashedHasher {
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+
+        for chunk in &mut chunks {
+            self.fold(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+
+        let remainder = chunks.remainder();
+
+        if !remainder.is_empty() {
+            let mut word = [0; 8];
+            word[..remainder.len()].copy_from_slice(remainder);
+            self.fold(u64::from_le_bytes(word));
+        }
+    }
+
+    #[inline(always)]
+    fn write_u8(&mut self, i: u8) {
+        self.write_u64(u64::from(i));
+    }
+
+    #[inline(always)]
+    fn write_u16(&mut self, i: u16) {
+        self.write_u64(u64::from(i));
+    }
+
+    #[inline(always)]
+    fn write_u32(&mut self, i: u32) {
+        self.write_u64(u64::from(i));
+    }
+
+    #[inline(always)]
+    fn write_u64(&mut self, i: u64) {
+        self.fold(i.wrapping_mul(MULTIPLIER));
+    }
+
+    #[inline(always)]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
@@ -0,0 +1,67 @@
+use core::{hash::BuildHasher, marker::PhantomData};
+
+use crate::{Sha256, SvmHasher, SyscallDigest, HASH_BYTES};
+
+/// A `BuildHasher` for `SvmHasher` that chains a 32 byte seed into the first
+/// hash of every key.
+///
+/// With the default `SvmBuildHasher` every map hashes the same key to the same
+/// value, so one set of colliding keys, ground once, works against every map.
+/// A seed makes the colliding keys depend on it, so they have to be ground
+/// again for every seed.
+///
+/// Neither the slot nor a recent blockhash is secret: the transaction is
+/// signed with its recent blockhash and `SlotHashes` can be read by anyone,
+/// so an attacker knows the seed before sending the keys and can still grind
+/// them for it. Deriving the seed from them only stops collisions that were
+/// ground ahead of time, or for another program, from being reused.
+pub struct SeededSvmBuildHasher<A = Sha256> {
+    seed: [u8; HASH_BYTES],
+    algorithm: PhantomData<fn() -> A>,
+}
+
+impl<A> SeededSvmBuildHasher<A> {
+    pub fn new(seed: [u8; HASH_BYTES]) -> Self {
+        Self {
+            seed,
+            algorithm: PhantomData,
+        }
+    }
+
+    pub fn seed(&self) -> &[u8; HASH_BYTES] {
+        &self.seed
+    }
+}
+
+impl<A: SyscallDigest> SeededSvmBuildHasher<A> {
+    /// Derives the seed by hashing `data`, e.g. parts of the instruction data
+    /// or of a sysvar.
+    pub fn from_data(data: &[&[u8]]) -> Self {
+        Self::new(A::hashv(data))
+    }
+
+    /// Derives the seed from a slot, e.g. the one in the `Clock` sysvar.
+    pub fn from_slot(slot: u64) -> Self {
+        Self::from_data(&[&slot.to_le_bytes()])
+    }
+
+    /// Derives the seed from a recent blockhash.
+    pub fn from_recent_blockhash(blockhash: &[u8; HASH_BYTES]) -> Self {
+        Self::from_data(&[blockhash])
+    }
+}
+
+impl<A> Clone for SeededSvmBuildHasher<A> {
+    fn clone(&self) -> Self {
+        Self::new(self.seed)
+    }
+}
+
+impl<A: SyscallDigest> BuildHasher for SeededSvmBuildHasher<A> {
+    type Hasher = SvmHasher<A>;
+
+    #[inline(always)]
+    fn build_hasher(&self) -> Self::Hasher {
+        SvmHasher::with_state(self.seed, true)
+    }
+}
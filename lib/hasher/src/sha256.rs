@@ -0,0 +1,54 @@
+use alloc::vec::Vec;
+
+use digest::{
+    consts::U32, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+use crate::{Sha256, SyscallDigest};
+
+/// `SHA256` with the `digest` traits, so code that is generic over a
+/// `D: Digest`, like a Merkle tree or a signature scheme, hashes with
+/// `sol_sha256` without changes.
+///
+/// The updates are collected in a growable buffer and hashed with a single
+/// `sol_sha256` call when the digest is finalized, however long the input is.
+#[derive(Clone, Default)]
+pub struct SvmSha256 {
+    buffer: Vec<u8>,
+}
+
+impl HashMarker for SvmSha256 {}
+
+impl OutputSizeUser for SvmSha256 {
+    type OutputSize = U32;
+}
+
+impl Update for SvmSha256 {
+    #[inline(always)]
+    fn update(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+}
+
+impl FixedOutput for SvmSha256 {
+    #[inline(always)]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&Sha256::hashv(&[&self.buffer]));
+    }
+}
+
+impl Reset for SvmSha256 {
+    // Keeps the allocation for the next input
+    #[inline(always)]
+    fn reset(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+impl FixedOutputReset for SvmSha256 {
+    #[inline(always)]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&Sha256::hashv(&[&self.buffer]));
+        Reset::reset(self);
+    }
+}
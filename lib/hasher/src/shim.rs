@@ -0,0 +1,128 @@
+//! Host implementations of the hashing syscalls with the same ABI as the
+//! runtime, `vals` points to `val_len` slices laid out as an address and a
+//! length. They are what `SyscallDigest::syscall` calls off-chain, and with
+//! the `syscall-shim` feature `hashv` calls `syscall` instead of `fallback`,
+//! so the unsafe code of the on-chain path can be tested and run under Miri.
+
+use sha2::Digest;
+
+use crate::{vectored::MAX_SLICES, HASH_BYTES};
+
+// How the runtime reads every slice
+#[repr(C)]
+struct SolBytes {
+    addr: *const u8,
+    len: u64,
+}
+
+// The runtime aborts the program on these, so the shim panics
+unsafe fn translate<'a>(vals: *const u8, val_len: u64) -> impl Iterator<Item = &'a [u8]> {
+    assert!(val_len <= MAX_SLICES as u64, "too many slices: {val_len}");
+
+    let vals = unsafe { core::slice::from_raw_parts(vals as *const SolBytes, val_len as usize) };
+
+    vals.iter()
+        .map(|val| unsafe { core::slice::from_raw_parts(val.addr, val.len as usize) })
+}
+
+/// # Safety
+///
+/// `vals` has to point to `val_len` valid slices, and `hash_result` to 32
+/// writable bytes.
+pub unsafe fn sol_sha256(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64 {
+    let mut sha256_hasher = sha2::Sha256::new();
+
+    for val in unsafe { translate(vals, val_len) } {
+        sha256_hasher.update(val);
+    }
+
+    let digest: [u8; HASH_BYTES] = sha256_hasher.finalize().into();
+
+    unsafe { hash_result.copy_from_nonoverlapping(digest.as_ptr(), HASH_BYTES) };
+
+    0
+}
+
+/// # Safety
+///
+/// Same as `sol_sha256`.
+pub unsafe fn sol_keccak256(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64 {
+    let mut keccak_hasher = sha3::Keccak256::new();
+
+    for val in unsafe { translate(vals, val_len) } {
+        keccak_hasher.update(val);
+    }
+
+    let digest: [u8; HASH_BYTES] = keccak_hasher.finalize().into();
+
+    unsafe { hash_result.copy_from_nonoverlapping(digest.as_ptr(), HASH_BYTES) };
+
+    0
+}
+
+/// # Safety
+///
+/// Same as `sol_sha256`.
+#[cfg(feature = "blake3")]
+pub unsafe fn sol_blake3(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64 {
+    let mut blake3_hasher = blake3::Hasher::new();
+
+    for val in unsafe { translate(vals, val_len) } {
+        blake3_hasher.update(val);
+    }
+
+    unsafe {
+        hash_result
+            .copy_from_nonoverlapping(blake3_hasher.finalize().as_bytes().as_ptr(), HASH_BYTES)
+    };
+
+    0
+}
+
+/// Only supports the BN254 x5 parameters, like the runtime. It returns 1 when
+/// an input is not a valid field element.
+///
+/// # Safety
+///
+/// Same as `sol_sha256`.
+#[cfg(feature = "poseidon")]
+pub unsafe fn sol_poseidon(
+    parameters: u64,
+    endianness: u64,
+    vals: *const u8,
+    val_len: u64,
+    hash_result: *mut u8,
+) -> u64 {
+    use ark_bn254::Fr;
+    use light_poseidon::{Poseidon, PoseidonBytesHasher};
+
+    assert_eq!(parameters, 0, "unsupported parameters: {parameters}");
+    assert!(
+        (1..=12).contains(&val_len),
+        "invalid number of inputs: {val_len}"
+    );
+
+    let mut inputs = [&[][..]; 12];
+
+    for (input, val) in inputs.iter_mut().zip(unsafe { translate(vals, val_len) }) {
+        *input = val;
+    }
+
+    let inputs = &inputs[..val_len as usize];
+
+    let mut poseidon_hasher = Poseidon::<Fr>::new_circom(inputs.len()).unwrap();
+
+    let digest = match endianness {
+        0 => poseidon_hasher.hash_bytes_be(inputs),
+        1 => poseidon_hasher.hash_bytes_le(inputs),
+        _ => panic!("unsupported endianness: {endianness}"),
+    };
+
+    match digest {
+        Ok(digest) => {
+            unsafe { hash_result.copy_from_nonoverlapping(digest.as_ptr(), HASH_BYTES) };
+            0
+        }
+        Err(_) => 1,
+    }
+}
@@ -0,0 +1,44 @@
+use sha2::Digest;
+
+use crate::{SvmBuildHasherWith, SvmHasher, SyscallDigest, HASH_BYTES};
+
+/// `SHA256` computed with `sha2` in the program, even on-chain, to compare the
+/// cost of `sol_sha256` against hashing without it.
+pub struct SoftwareSha256;
+
+impl SoftwareSha256 {
+    fn software(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+        let mut sha256_hasher = sha2::Sha256::new();
+
+        for val in vals {
+            sha256_hasher.update(val);
+        }
+
+        sha256_hasher.finalize().into()
+    }
+}
+
+impl SyscallDigest for SoftwareSha256 {
+    // `hashv` is overridden, so the hasher does not call this, `vals` are read
+    // as `&[u8]`s, which is how `hashv` lays them out for a syscall
+    unsafe fn syscall(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64 {
+        let vals = unsafe { core::slice::from_raw_parts(vals as *const &[u8], val_len as usize) };
+
+        unsafe { hash_result.copy_from_nonoverlapping(Self::software(vals).as_ptr(), HASH_BYTES) };
+
+        0
+    }
+
+    fn fallback(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+        Self::software(vals)
+    }
+
+    #[inline(always)]
+    fn hashv(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+        Self::software(vals)
+    }
+}
+
+pub type SvmSoftwareBuildHasher = SvmBuildHasherWith<SoftwareSha256>;
+
+pub type SvmSoftwareSHA256Hasher = SvmHasher<SoftwareSha256>;
@@ -0,0 +1,103 @@
+use core::marker::PhantomData;
+
+use crate::{Sha256, SyscallDigest, HASH_BYTES};
+
+/// The most slices the runtime accepts in a single `sol_sha256` call, the
+/// slices themselves have no length limit beyond what they cost to hash.
+pub const MAX_SLICES: usize = 20_000;
+
+/// Hashes all the `vals` as one input with `sol_sha256`, without copying them.
+///
+/// For up to `MAX_SLICES` slices this is the `SHA256` of their concatenation.
+/// More slices than that are hashed in chunks of `MAX_SLICES`, and the digest
+/// of every chunk is chained into the digest of the next one.
+#[inline(always)]
+pub fn hashv(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+    hashv_with::<Sha256>(vals)
+}
+
+/// Same as `hashv`, with any `SyscallDigest`.
+pub fn hashv_with<A: SyscallDigest>(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+    let mut chunks = vals.chunks(MAX_SLICES);
+
+    let mut hash_result = A::hashv(chunks.next().unwrap_or(&[]));
+
+    for chunk in chunks {
+        let digest = A::hashv(chunk);
+        hash_result = A::hashv(&[&hash_result, &digest]);
+    }
+
+    hash_result
+}
+
+/// Collects borrowed slices so they can be hashed together with a single
+/// syscall, without concatenating them first.
+///
+/// Up to `N` slices are kept, once there are more the ones collected so far
+/// are hashed and their digest takes the first place of the next call.
+pub struct HashSlices<'a, A = Sha256, const N: usize = 16> {
+    slices: [&'a [u8]; N],
+    slices_len: usize,
+    state: [u8; HASH_BYTES],
+    is_used: bool,
+    algorithm: PhantomData<fn() -> A>,
+}
+
+impl<A, const N: usize> Default for HashSlices<'_, A, N> {
+    fn default() -> Self {
+        Self {
+            slices: [&[]; N],
+            slices_len: 0,
+            state: [0; HASH_BYTES],
+            is_used: false,
+            algorithm: PhantomData,
+        }
+    }
+}
+
+impl<'a, A: SyscallDigest, const N: usize> HashSlices<'a, A, N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, slice: &'a [u8]) -> &mut Self {
+        const { assert!(N >= 2, "a chained call needs room for a slice") };
+
+        // Once chained, one place is taken by the previous digest
+        let capacity = if self.is_used { N - 1 } else { N };
+
+        if self.slices_len == capacity {
+            self.state = self.digest();
+            self.is_used = true;
+            self.slices_len = 0;
+        }
+
+        self.slices[self.slices_len] = slice;
+        self.slices_len += 1;
+
+        self
+    }
+
+    pub fn extend<I: IntoIterator<Item = &'a [u8]>>(&mut self, slices: I) -> &mut Self {
+        for slice in slices {
+            self.push(slice);
+        }
+
+        self
+    }
+
+    /// Hashes the collected slices, it is the `SHA256` of their concatenation
+    /// for `Sha256` as long as no more than `N` slices were pushed.
+    pub fn digest(&self) -> [u8; HASH_BYTES] {
+        if !self.is_used {
+            return A::hashv(&self.slices[..self.slices_len]);
+        }
+
+        let mut data: [&[u8]; N] = [&[]; N];
+
+        data[0] = &self.state;
+        data[1..=self.slices_len].copy_from_slice(&self.slices[..self.slices_len]);
+
+        A::hashv(&data[..=self.slices_len])
+    }
+}
@@ -0,0 +1,905 @@
+use core::hash::{Hash, Hasher};
+
+use sha2::{Digest, Sha256};
+
+use svm_hasher::{
+    cost, hashv, HashSlices, Hasher256, SvmBufferedHasher, SvmBufferedSHA256Hasher,
+    SvmCanonicalHasher, SvmHasher, SvmKeccakHasher, SvmSHA256Hasher, SyscallDigest, MAX_SLICES,
+};
+
+mod vectors;
+
+#[derive(Hash)]
+struct Empty;
+
+#[derive(Hash)]
+struct Key {
+    id: u64,
+    owner: [u8; 32],
+}
+
+// Hashes `key` the way a map does, so keys with empty `Hash` impls can be used
+fn hash_key<K: Hash>(key: &K) -> SvmSHA256Hasher {
+    let mut hasher = SvmSHA256Hasher::default();
+    key.hash(&mut hasher);
+    hasher
+}
+
+fn empty_digest() -> [u8; 32] {
+    Sha256::digest([]).into()
+}
+
+#[test]
+fn test_finish_without_write() {
+    let hasher = SvmSHA256Hasher::default();
+
+    assert_eq!(hasher.finish_digest(), empty_digest());
+    assert_eq!(
+        hasher.finish(),
+        u64::from_le_bytes(empty_digest()[..8].try_into().unwrap())
+    );
+}
+
+#[test]
+fn test_empty_hash_impls() {
+    let unit_hasher = hash_key(&());
+    let empty_hasher = hash_key(&Empty);
+
+    assert_eq!(unit_hasher.finish_digest(), empty_digest());
+    assert_eq!(empty_hasher.finish_digest(), empty_digest());
+    assert_eq!(unit_hasher.finish(), empty_hasher.finish());
+
+    // The other hashers have to handle it as well
+    let _ = SvmKeccakHasher::default().finish();
+    let _ = SvmBufferedSHA256Hasher::default().finish();
+}
+
+#[test]
+fn test_write_is_chained() {
+    let mut hasher = SvmSHA256Hasher::default();
+    hasher.write(b"first");
+    hasher.write(b"second");
+
+    let first: [u8; 32] = Sha256::digest(b"first").into();
+    let second: [u8; 32] = Sha256::new()
+        .chain_update(first)
+        .chain_update(b"second")
+        .finalize()
+        .into();
+
+    assert_eq!(hasher.finish_digest(), second);
+}
+
+#[test]
+fn test_clone_round_trip() {
+    let key = Key {
+        id: 7,
+        owner: [3; 32],
+    };
+
+    let mut hasher = SvmSHA256Hasher::default();
+    key.id.hash(&mut hasher);
+
+    let mut cloned_hasher = hasher.clone();
+
+    assert_eq!(hasher.finish_digest(), cloned_hasher.finish_digest());
+
+    key.owner.hash(&mut hasher);
+    key.owner.hash(&mut cloned_hasher);
+
+    assert_eq!(hasher.finish_digest(), cloned_hasher.finish_digest());
+
+    let mut key_hasher = SvmSHA256Hasher::default();
+    key.hash(&mut key_hasher);
+
+    assert_eq!(hasher.finish(), key_hasher.finish());
+
+    // A clone of an unused hasher is still unused
+    let cloned_hasher = SvmSHA256Hasher::default().clone();
+
+    assert_eq!(cloned_hasher.finish_digest(), empty_digest());
+}
+
+#[test]
+fn test_default_round_trip() {
+    let mut hasher = SvmSHA256Hasher::default();
+    [1u8, 2, 3].hash(&mut hasher);
+
+    let mut other_hasher = SvmSHA256Hasher::default();
+    [1u8, 2, 3].hash(&mut other_hasher);
+
+    assert_eq!(hasher.finish(), other_hasher.finish());
+    assert_ne!(hasher.finish(), SvmSHA256Hasher::default().finish());
+}
+
+#[test]
+fn test_build_hasher() {
+    use core::hash::BuildHasher;
+    use svm_hasher::{SvmBuildHasher, SvmBuildHasherWith};
+
+    // Without the algorithm spelled out, like before `SvmBuildHasherWith`
+    let hash_builder = SvmBuildHasher::default();
+
+    assert_eq!(
+        hash_builder.hash_one([1u8; 32]),
+        hash_key(&[1u8; 32]).finish()
+    );
+    assert_eq!(
+        SvmBuildHasherWith::<svm_hasher::Sha256>::default().hash_one([1u8; 32]),
+        hash_builder.hash_one([1u8; 32])
+    );
+}
+
+#[test]
+fn test_integer_writes_are_batched() {
+    let key = (7u64, [3u8; 32], 9u16);
+
+    // The `u64` and the length prefix of the array are hashed with the array
+    // itself, and the `u16` in `finish`
+    let first: [u8; 32] = Sha256::new()
+        .chain_update(7u64.to_ne_bytes())
+        .chain_update(32usize.to_ne_bytes())
+        .chain_update([3u8; 32])
+        .finalize()
+        .into();
+    let second: [u8; 32] = Sha256::new()
+        .chain_update(first)
+        .chain_update(9u16.to_ne_bytes())
+        .finalize()
+        .into();
+
+    assert_eq!(hash_key(&key).finish_digest(), second);
+
+    // Integers on their own are hashed in a single call as well
+    let integers: [u8; 32] = Sha256::new()
+        .chain_update(1u32.to_ne_bytes())
+        .chain_update(2u64.to_ne_bytes())
+        .finalize()
+        .into();
+
+    assert_eq!(hash_key(&(1u32, 2u64)).finish_digest(), integers);
+}
+
+#[test]
+fn test_buffered_digest() {
+    let key = (7u64, [3u8; 32], 9u16);
+
+    let mut hasher = SvmBufferedSHA256Hasher::default();
+    key.hash(&mut hasher);
+
+    // The whole key fits in the buffer, so it is hashed with a single call
+    let digest: [u8; 32] = Sha256::new()
+        .chain_update(7u64.to_ne_bytes())
+        .chain_update(32usize.to_ne_bytes())
+        .chain_update([3u8; 32])
+        .chain_update(9u16.to_ne_bytes())
+        .finalize()
+        .into();
+
+    assert_eq!(hasher.finish_digest(), digest);
+    assert_eq!(
+        SvmBufferedSHA256Hasher::default().finish_digest(),
+        empty_digest()
+    );
+}
+
+#[test]
+fn test_buffered_overflow() {
+    let mut hasher = SvmBufferedHasher::<svm_hasher::Sha256, 16>::default();
+    hasher.write(&[1; 10]);
+    hasher.write(&[2; 10]);
+    hasher.write(&[3; 4]);
+    hasher.write(&[4; 16]);
+
+    // The second write does not fit, so the buffer and the write are hashed
+    // into the state, and the state is chained into the next overflow. With
+    // nothing written after it, that state is the digest
+    let first: [u8; 32] = Sha256::new()
+        .chain_update([1u8; 10])
+        .chain_update([2u8; 10])
+        .finalize()
+        .into();
+    let second: [u8; 32] = Sha256::new()
+        .chain_update(first)
+        .chain_update([3u8; 4])
+        .chain_update([4u8; 16])
+        .finalize()
+        .into();
+
+    assert_eq!(hasher.finish_digest(), second);
+
+    hasher.write(&[5; 2]);
+
+    let chained: [u8; 32] = Sha256::new()
+        .chain_update(second)
+        .chain_update([5u8; 2])
+        .finalize()
+        .into();
+
+    assert_eq!(hasher.finish_digest(), chained);
+
+    // A key that overflows with its last write is hashed in one piece, like
+    // `SvmSHA256Hasher` hashes it
+    let key = &[6u8; 100][..];
+
+    let mut hasher = SvmBufferedSHA256Hasher::default();
+    key.hash(&mut hasher);
+
+    assert_eq!(hasher.finish_digest(), hash_key(&key).finish_digest());
+}
+
+// Checks the digest against a known one rather than against the library the
+// off-chain fallback uses
+fn hex_digest(hex: &str) -> [u8; 32] {
+    let mut digest = [0; 32];
+
+    for (byte, hex) in digest.iter_mut().zip(hex.as_bytes().chunks_exact(2)) {
+        *byte = u8::from_str_radix(core::str::from_utf8(hex).unwrap(), 16).unwrap();
+    }
+
+    digest
+}
+
+#[test]
+fn test_keccak_digest() {
+    let mut hasher = SvmKeccakHasher::default();
+
+    assert_eq!(
+        hasher.finish_digest(),
+        hex_digest("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+    );
+
+    hasher.write(b"abc");
+
+    let abc = hex_digest("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45");
+
+    assert_eq!(hasher.finish_digest(), abc);
+
+    // The second write is chained to the digest of the first
+    hasher.write(b"def");
+
+    let chained: [u8; 32] = sha3::Keccak256::new()
+        .chain_update(abc)
+        .chain_update(b"def")
+        .finalize()
+        .into();
+
+    assert_eq!(hasher.finish_digest(), chained);
+}
+
+#[test]
+#[cfg(feature = "blake3")]
+fn test_blake3_digest() {
+    use svm_hasher::SvmBlake3Hasher;
+
+    let mut hasher = SvmBlake3Hasher::default();
+
+    assert_eq!(
+        hasher.finish_digest(),
+        hex_digest("af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262")
+    );
+
+    hasher.write(b"abc");
+
+    assert_eq!(
+        hasher.finish_digest(),
+        hex_digest("6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85")
+    );
+}
+
+// Pads, chunks and chains `bytes` the way `SvmPoseidonHasher` documents it,
+// with `light_poseidon` directly
+#[cfg(feature = "poseidon")]
+fn poseidon_digest(bytes: &[u8]) -> [u8; 32] {
+    use ark_bn254::Fr;
+    use light_poseidon::{Poseidon, PoseidonBytesHasher};
+
+    let hash = |inputs: &[[u8; 32]]| {
+        let inputs: Vec<&[u8]> = inputs.iter().map(|input| &input[..]).collect();
+
+        Poseidon::<Fr>::new_circom(inputs.len())
+            .unwrap()
+            .hash_bytes_be(&inputs)
+            .unwrap()
+    };
+
+    let mut padded = bytes.to_vec();
+    padded.push(1);
+    padded.resize(padded.len().div_ceil(31) * 31, 0);
+
+    let elements: Vec<[u8; 32]> = padded
+        .chunks_exact(31)
+        .map(|chunk| {
+            let mut element = [0; 32];
+            element[1..].copy_from_slice(chunk);
+            element
+        })
+        .collect();
+
+    // The first call takes 12 elements, every call after it takes the digest
+    // of the previous one and 11 more
+    let (first, mut rest) = elements.split_at(elements.len().min(12));
+    let mut digest = hash(first);
+
+    while !rest.is_empty() {
+        let (next, next_rest) = rest.split_at(rest.len().min(11));
+
+        let mut inputs = vec![digest];
+        inputs.extend_from_slice(next);
+
+        digest = hash(&inputs);
+        rest = next_rest;
+    }
+
+    digest
+}
+
+#[test]
+#[cfg(feature = "poseidon")]
+fn test_poseidon_digest() {
+    use svm_hasher::SvmPoseidonHasher;
+
+    // Around a chunk, around a full call of 12 elements, and long enough to
+    // chain several calls
+    for len in [
+        0,
+        1,
+        30,
+        31,
+        32,
+        11 * 31,
+        12 * 31 - 1,
+        12 * 31,
+        12 * 31 + 1,
+        23 * 31,
+        1000,
+    ] {
+        let bytes: Vec<u8> = (0..len).map(|i| (i * 31 + 7) as u8).collect();
+
+        let mut hasher = SvmPoseidonHasher::default();
+        hasher.write(&bytes);
+
+        let digest = poseidon_digest(&bytes);
+
+        assert_eq!(hasher.finish_digest(), digest, "{len}");
+        assert_eq!(
+            hasher.finish(),
+            u64::from_le_bytes(digest[24..].try_into().unwrap()),
+            "{len}"
+        );
+
+        // Where the writes are split does not matter
+        let mut split_hasher = SvmPoseidonHasher::default();
+
+        for part in bytes.chunks(7) {
+            split_hasher.write(part);
+        }
+
+        assert_eq!(split_hasher.finish_digest(), digest, "{len}");
+    }
+}
+
+#[test]
+fn test_adaptive_threshold() {
+    use svm_hasher::AdaptiveHasher;
+
+    let adaptive_hash = |writes: &[&[u8]]| {
+        let mut hasher = AdaptiveHasher::<16>::default();
+
+        for bytes in writes {
+            hasher.write(bytes);
+        }
+
+        hasher.finish()
+    };
+
+    // Up to the threshold the mixer is used, so the hash is not the `SHA256`
+    // one, and it still depends on the length
+    let mixed = adaptive_hash(&[&[1; 16]]);
+
+    assert_ne!(
+        mixed,
+        u64::from_le_bytes(Sha256::digest([1u8; 16])[..8].try_into().unwrap())
+    );
+    assert_eq!(mixed, adaptive_hash(&[&[1; 16]]));
+    assert_ne!(mixed, adaptive_hash(&[&[1; 15]]));
+
+    // One byte over it the input is hashed with a single `sol_sha256` call
+    assert_eq!(
+        adaptive_hash(&[&[1; 17]]),
+        u64::from_le_bytes(Sha256::digest([1u8; 17])[..8].try_into().unwrap())
+    );
+
+    // The choice is made on the total length, not on each write
+    assert_eq!(adaptive_hash(&[&[1; 10], &[1; 6]]), mixed);
+    assert_eq!(
+        adaptive_hash(&[&[1; 10], &[1; 7]]),
+        adaptive_hash(&[&[1; 17]])
+    );
+}
+
+#[test]
+fn test_hashv() {
+    let vals: [&[u8]; 3] = [b"first", b"", b"second"];
+
+    assert_eq!(
+        hashv(&vals),
+        <[u8; 32]>::from(Sha256::digest(b"firstsecond"))
+    );
+    assert_eq!(hashv(&[]), empty_digest());
+
+    // Past `MAX_SLICES` every chunk is hashed on its own and chained
+    let vals = vec![&b"a"[..]; MAX_SLICES + 1];
+
+    let first: [u8; 32] = Sha256::digest(vec![b'a'; MAX_SLICES]).into();
+    let second: [u8; 32] = Sha256::digest(b"a").into();
+    let chained: [u8; 32] = Sha256::new()
+        .chain_update(first)
+        .chain_update(second)
+        .finalize()
+        .into();
+
+    assert_eq!(hashv(&vals), chained);
+}
+
+#[test]
+fn test_hash_slices() {
+    let mut slices = HashSlices::<svm_hasher::Sha256, 4>::new();
+    slices.extend([&b"a"[..], b"b", b"c", b"d"]);
+
+    assert_eq!(slices.digest(), <[u8; 32]>::from(Sha256::digest(b"abcd")));
+
+    // The fifth slice does not fit, so the first four are chained
+    slices.push(b"e");
+
+    let first: [u8; 32] = Sha256::digest(b"abcd").into();
+    let chained: [u8; 32] = Sha256::new()
+        .chain_update(first)
+        .chain_update(b"e")
+        .finalize()
+        .into();
+
+    assert_eq!(slices.digest(), chained);
+}
+
+#[test]
+fn test_canonical_digest() {
+    let key = (7u64, vec![3u8; 20]);
+
+    let mut hasher = SvmCanonicalHasher::<32>::default();
+    key.hash(&mut hasher);
+
+    let mut input = vec![];
+    input.extend_from_slice(&7u64.to_ne_bytes());
+    input.extend_from_slice(&20usize.to_ne_bytes());
+    input.extend_from_slice(&[3u8; 20]);
+
+    assert_eq!(
+        hasher.finish_digest(),
+        <[u8; 32]>::from(Sha256::digest(&input))
+    );
+
+    // Going over the buffer does not change the digest
+    let mut hasher = SvmCanonicalHasher::<32>::default();
+    key.hash(&mut hasher);
+    key.hash(&mut hasher);
+
+    input.extend_from_slice(&input.clone());
+
+    assert_eq!(
+        hasher.finish_digest(),
+        <[u8; 32]>::from(Sha256::digest(&input))
+    );
+
+    assert_eq!(
+        SvmCanonicalHasher::<32>::default().finish_digest(),
+        empty_digest()
+    );
+}
+
+#[test]
+fn test_golden_vectors() {
+    for vector in vectors::VECTORS {
+        let mut hasher = SvmSHA256Hasher::default();
+        vector.apply(&mut hasher);
+
+        assert_eq!(hasher.finish(), vector.finish, "{}", vector.name);
+    }
+}
+
+#[test]
+fn test_cost() {
+    // The base cost and the minimum charge of the length prefix and of the
+    // 32 bytes
+    assert_eq!(cost::key_cost(&[0u8; 32]), 85 + 10 + 16);
+    assert_eq!(cost::key_cost(&[0u8; 32]), cost::slice_cost(32));
+    assert_eq!(cost::write_cost(100), 85 + 50);
+
+    // Keys that only write integers are hashed once in `finish`
+    assert_eq!(cost::key_cost(&7u64), 85 + 10);
+    assert_eq!(cost::key_cost(&()), 85);
+
+    // The integers are hashed with the byte write that follows them
+    assert_eq!(
+        cost::key_cost(&Key {
+            id: 7,
+            owner: [3; 32],
+        }),
+        cost::syscall_cost(&[8 + 8, 32])
+    );
+
+    // The second byte write is chained to the state of the first
+    assert_eq!(
+        cost::key_cost(&([1u8; 4], [2u8; 4])),
+        cost::syscall_cost(&[8, 4]) + cost::syscall_cost(&[32, 8, 4])
+    );
+}
+
+#[test]
+#[cfg(feature = "metrics")]
+fn test_metrics() {
+    use svm_hasher::{key_metrics, Metrics};
+
+    // A `Pubkey` like key is hashed with its length prefix in one syscall
+    assert_eq!(
+        key_metrics(&[0u8; 32]),
+        Metrics {
+            syscalls: 1,
+            bytes: 8 + 32,
+        }
+    );
+
+    // Every byte write after the first one chains the state
+    assert_eq!(
+        key_metrics(&([1u8; 4], [2u8; 4])),
+        Metrics {
+            syscalls: 2,
+            bytes: (8 + 4) + (32 + 8 + 4),
+        }
+    );
+
+    assert_eq!(key_metrics(&()).syscalls, 1);
+    assert_eq!(
+        key_metrics(&(1u64, 2u64, 3u64, 4u64, 5u64)),
+        Metrics {
+            syscalls: 2,
+            bytes: 32 + (32 + 8),
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "metrics")]
+fn test_metered_build_hasher() {
+    use std::{cell::Cell, collections::HashMap};
+    use svm_hasher::{key_metrics, MeteredBuildHasher, Metrics};
+
+    let metrics = Cell::new(Metrics::default());
+
+    // With enough capacity the map does not grow, so every key is hashed once
+    // for its insert and once for its lookup
+    let build_hasher: MeteredBuildHasher = MeteredBuildHasher::new(&metrics);
+    let mut map = HashMap::with_capacity_and_hasher(16, build_hasher);
+    let keys = [[1u8; 32], [2u8; 32], [3u8; 32]];
+
+    for (index, key) in keys.iter().enumerate() {
+        map.insert(*key, index);
+    }
+
+    for (index, key) in keys.iter().enumerate() {
+        assert_eq!(map.get(key), Some(&index));
+    }
+
+    let key_metrics = key_metrics(&[0u8; 32]);
+
+    assert_eq!(
+        metrics.get(),
+        Metrics {
+            syscalls: 6 * key_metrics.syscalls,
+            bytes: 6 * key_metrics.bytes,
+        }
+    );
+}
+
+// Written like an implementation in another crate, without any `cfg`s, so it
+// has to compile with every combination of features
+struct DownstreamKeccak256;
+
+impl SyscallDigest for DownstreamKeccak256 {
+    unsafe fn syscall(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64 {
+        unsafe { svm_hasher::shim::sol_keccak256(vals, val_len, hash_result) }
+    }
+
+    fn fallback(vals: &[&[u8]]) -> [u8; 32] {
+        let mut keccak_hasher = sha3::Keccak256::new();
+
+        for val in vals {
+            keccak_hasher.update(val);
+        }
+
+        keccak_hasher.finalize().into()
+    }
+}
+
+#[test]
+fn test_downstream_digest() {
+    let key = ([3u8; 32], 7u64, "abc");
+
+    let mut hasher = SvmHasher::<DownstreamKeccak256>::default();
+    key.hash(&mut hasher);
+
+    let mut keccak_hasher = SvmKeccakHasher::default();
+    key.hash(&mut keccak_hasher);
+
+    assert_eq!(hasher.finish_digest(), keccak_hasher.finish_digest());
+}
+
+#[test]
+#[should_panic(expected = "too many slices")]
+fn test_shim_slice_limit() {
+    let vals = vec![&b"a"[..]; MAX_SLICES + 1];
+    let mut hash_result = [0u8; 32];
+
+    // The runtime does not accept more than `MAX_SLICES` slices either
+    unsafe {
+        svm_hasher::shim::sol_sha256(
+            vals.as_ptr() as *const u8,
+            vals.len() as u64,
+            hash_result.as_mut_ptr(),
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_sha256_digest() {
+    use svm_hasher::SvmSha256;
+
+    // Inputs of a few KB are hashed in one piece like short ones
+    for len in [0, 1, 55, 64, 128, 129, 1000, 4096, 10_000] {
+        let data = vec![7u8; len];
+
+        assert_eq!(SvmSha256::digest(&data), Sha256::digest(&data));
+    }
+
+    let mut digest = SvmSha256::new();
+    digest.update([1u8; 20]);
+    digest.update([2u8; 2000]);
+
+    let mut sha256_digest = Sha256::new();
+    sha256_digest.update([1u8; 20]);
+    sha256_digest.update([2u8; 2000]);
+
+    assert_eq!(digest.finalize_reset(), sha256_digest.finalize());
+    assert_eq!(digest.finalize(), Sha256::digest([]));
+}
+
+#[test]
+#[cfg(feature = "software-sha256")]
+fn test_software_sha256() {
+    use svm_hasher::SvmSoftwareSHA256Hasher;
+
+    let key = Key {
+        id: 7,
+        owner: [3; 32],
+    };
+
+    let mut software_hasher = SvmSoftwareSHA256Hasher::default();
+    key.hash(&mut software_hasher);
+
+    assert_eq!(
+        software_hasher.finish_digest(),
+        hash_key(&key).finish_digest()
+    );
+}
+
+#[test]
+fn test_seeded_build_hasher() {
+    use core::hash::BuildHasher;
+    use svm_hasher::{SeededSvmBuildHasher, SvmBuildHasher};
+
+    let seed = [9u8; 32];
+    let key = [1u8; 32];
+
+    // The seed is chained in as if it was the state of a previous write, so
+    // the first syscall hashes it with the length prefix and the bytes
+    let mut hasher = <SeededSvmBuildHasher>::new(seed).build_hasher();
+    key.hash(&mut hasher);
+
+    let expected: [u8; 32] = Sha256::new()
+        .chain_update(seed)
+        .chain_update(32usize.to_ne_bytes())
+        .chain_update(key)
+        .finalize()
+        .into();
+
+    assert_eq!(hasher.finish_digest(), expected);
+
+    // Different seeds hash the same key differently
+    assert_ne!(
+        <SeededSvmBuildHasher>::new(seed).hash_one(key),
+        <SeededSvmBuildHasher>::new([8; 32]).hash_one(key)
+    );
+    assert_ne!(
+        <SeededSvmBuildHasher>::new(seed).hash_one(key),
+        SvmBuildHasher::default().hash_one(key)
+    );
+
+    // The derived seeds are the digest of their data
+    let blockhash = [4u8; 32];
+
+    assert_eq!(
+        <SeededSvmBuildHasher>::from_slot(7).seed(),
+        &<[u8; 32]>::from(Sha256::digest(7u64.to_le_bytes()))
+    );
+    assert_eq!(
+        <SeededSvmBuildHasher>::from_recent_blockhash(&blockhash).seed(),
+        &<[u8; 32]>::from(Sha256::digest(blockhash))
+    );
+    assert_eq!(
+        <SeededSvmBuildHasher>::from_data(&[b"ab", b"c"]).seed(),
+        &<[u8; 32]>::from(Sha256::digest(b"abc"))
+    );
+
+    // And they are the same every time
+    assert_eq!(
+        <SeededSvmBuildHasher>::from_slot(7).hash_one(key),
+        <SeededSvmBuildHasher>::from_slot(7).hash_one(key)
+    );
+    assert_ne!(
+        <SeededSvmBuildHasher>::from_slot(7).hash_one(key),
+        <SeededSvmBuildHasher>::from_slot(8).hash_one(key)
+    );
+}
+
+#[test]
+fn test_prehashed_hasher() {
+    use std::collections::HashSet;
+    use svm_hasher::{Fixed, PrehashedHasher};
+
+    let prehashed_hash = |key: &dyn Fn(&mut PrehashedHasher)| {
+        let mut hasher = PrehashedHasher::default();
+        key(&mut hasher);
+        hasher.finish()
+    };
+
+    // Keys that only write a small length prefix, like empty `Vec`s of
+    // different types or a unit enum variant, are spread over the whole `u64`
+    let prefix_hashes: Vec<u64> = (0..128usize)
+        .map(|len| prehashed_hash(&|hasher| hasher.write_usize(len)))
+        .collect();
+
+    let distinct: HashSet<u64> = prefix_hashes.iter().copied().collect();
+    assert_eq!(distinct.len(), prefix_hashes.len());
+
+    // `hashbrown` takes the control byte from the top 7 bits and the bucket
+    // from the low bits, neither may be left mostly zero
+    let top_bits: HashSet<u64> = prefix_hashes.iter().map(|hash| hash >> 57).collect();
+    let low_bits: HashSet<u64> = prefix_hashes.iter().map(|hash| hash & 127).collect();
+    assert!(
+        top_bits.len() >= 64,
+        "{} distinct h2 values",
+        top_bits.len()
+    );
+    assert_eq!(low_bits.len(), 128);
+
+    // A `[u8; 32]` is its multiplied length prefix followed by its four
+    // words, folded the same way every time
+    let key: [u8; 32] = core::array::from_fn(|index| index as u8);
+
+    let mut expected = 32u64.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+
+    for word in key.chunks_exact(8) {
+        expected = expected.rotate_left(23) ^ u64::from_le_bytes(word.try_into().unwrap());
+    }
+
+    assert_eq!(prehashed_hash(&|hasher| key.hash(hasher)), expected);
+    assert_eq!(
+        prehashed_hash(&|hasher| key.hash(hasher)),
+        prehashed_hash(&|hasher| key.hash(hasher))
+    );
+
+    // Folding the bytes does not cancel the prefix, the same bytes with and
+    // without it, and zeros of different lengths, hash differently
+    assert_ne!(
+        prehashed_hash(&|hasher| key.hash(hasher)),
+        prehashed_hash(&|hasher| Fixed(key).hash(hasher))
+    );
+
+    let zero_hashes: HashSet<u64> = [8usize, 16, 24, 32, 64]
+        .into_iter()
+        .map(|len| prehashed_hash(&|hasher| vec![0u8; len].hash(hasher)))
+        .collect();
+
+    assert_eq!(zero_hashes.len(), 5);
+    assert!(!zero_hashes.contains(&0));
+}
+
+#[test]
+fn test_fast_hasher() {
+    use svm_hasher::SvmFastHasher;
+
+    let fast_hash = |bytes: &[u8]| {
+        let mut hasher = SvmFastHasher::default();
+        hasher.write(bytes);
+        hasher.finish()
+    };
+
+    // There is no random state
+    assert_eq!(fast_hash(b"key"), fast_hash(b"key"));
+
+    // Trailing zeros are not lost in the padding
+    assert_ne!(fast_hash(&[0]), fast_hash(&[0, 0]));
+    assert_ne!(fast_hash(&[]), fast_hash(&[0]));
+    assert_ne!(fast_hash(&[1; 8]), fast_hash(&[1; 9]));
+
+    // A length in the padding could be written as data too
+    assert_ne!(fast_hash(&[5]), fast_hash(&[5, 0, 0, 0, 0, 0, 0, 1]));
+
+    // Keys that can hold any bytes, like `str`, are affected the same way
+    let fast_hash_one = |key: &str| {
+        let mut hasher = SvmFastHasher::default();
+        key.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    assert_ne!(
+        fast_hash_one("\u{5}"),
+        fast_hash_one("\u{5}\0\0\0\0\0\0\u{1}")
+    );
+}
+
+#[test]
+fn test_bounded_hasher() {
+    use core::hash::BuildHasher;
+    use svm_hasher::BoundedBuildHasher;
+
+    let hash_builder = <BoundedBuildHasher>::new(16);
+
+    let mut key = vec![1u8; 1000];
+    let mut other_key = key.clone();
+    other_key[500] = 2;
+
+    // Only the middle differs, so the keys collide
+    assert_eq!(
+        hash_builder.hash_one(&key),
+        hash_builder.hash_one(&other_key)
+    );
+
+    other_key[999] = 2;
+    assert_ne!(
+        hash_builder.hash_one(&key),
+        hash_builder.hash_one(&other_key)
+    );
+
+    // The length is still hashed
+    let long_key = vec![1u8; 1001];
+    assert_ne!(
+        hash_builder.hash_one(&key),
+        hash_builder.hash_one(&long_key)
+    );
+
+    // Short keys are hashed as they are
+    key.truncate(32);
+    assert_eq!(hash_builder.hash_one(&key), hash_key(&key).finish());
+}
+
+#[test]
+fn test_fixed_key() {
+    use svm_hasher::Fixed;
+
+    let pubkey = [3u8; 32];
+
+    // A single slice without the length prefix
+    assert_eq!(
+        hash_key(&Fixed(pubkey)).finish_digest(),
+        <[u8; 32]>::from(Sha256::digest(pubkey))
+    );
+    assert_eq!(cost::key_cost(&Fixed(pubkey)), cost::syscall_cost(&[32]));
+    assert!(cost::key_cost(&Fixed(pubkey)) < cost::key_cost(&pubkey));
+
+    assert_eq!(
+        hash_key(&Fixed(7u64)).finish_digest(),
+        <[u8; 32]>::from(Sha256::digest(7u64.to_le_bytes()))
+    );
+}
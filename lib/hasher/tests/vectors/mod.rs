@@ -0,0 +1,243 @@
+// Golden `finish` values of `SvmSHA256Hasher`, shared by the host tests and
+// the test program, so the `sol_sha256` path and the `sha2` path are checked
+// against the same values. They must not be regenerated to make a test pass.
+
+use core::hash::Hasher;
+
+pub enum Write {
+    // The first `len` bytes of `pattern`
+    Bytes(usize),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+}
+
+pub struct Vector {
+    pub name: &'static str,
+    pub writes: &'static [Write],
+    pub finish: u64,
+}
+
+impl Vector {
+    pub fn apply<H: Hasher>(&self, hasher: &mut H) {
+        for write in self.writes {
+            match *write {
+                Write::Bytes(len) => hasher.write(&pattern(len)),
+                Write::U8(i) => hasher.write_u8(i),
+                Write::U16(i) => hasher.write_u16(i),
+                Write::U32(i) => hasher.write_u32(i),
+                Write::U64(i) => hasher.write_u64(i),
+                Write::U128(i) => hasher.write_u128(i),
+            }
+        }
+    }
+}
+
+pub fn pattern(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 31 + 7) as u8).collect()
+}
+
+pub const VECTORS: &[Vector] = &[
+    Vector {
+        name: "empty",
+        writes: &[],
+        finish: 0x141c_fc98_42c4_b0e3,
+    },
+    // Single writes
+    Vector {
+        name: "bytes_0",
+        writes: &[Write::Bytes(0)],
+        finish: 0x141c_fc98_42c4_b0e3,
+    },
+    Vector {
+        name: "bytes_1",
+        writes: &[Write::Bytes(1)],
+        finish: 0x6c7e_d2f6_5887_35ca,
+    },
+    Vector {
+        name: "bytes_2",
+        writes: &[Write::Bytes(2)],
+        finish: 0x9339_9781_1b81_0d14,
+    },
+    Vector {
+        name: "bytes_3",
+        writes: &[Write::Bytes(3)],
+        finish: 0x4274_1996_a274_7664,
+    },
+    Vector {
+        name: "bytes_7",
+        writes: &[Write::Bytes(7)],
+        finish: 0x04c8_0ebf_0334_ce13,
+    },
+    Vector {
+        name: "bytes_8",
+        writes: &[Write::Bytes(8)],
+        finish: 0xfc32_583f_ca00_b94f,
+    },
+    Vector {
+        name: "bytes_9",
+        writes: &[Write::Bytes(9)],
+        finish: 0x7267_15e8_ad14_4d1a,
+    },
+    Vector {
+        name: "bytes_15",
+        writes: &[Write::Bytes(15)],
+        finish: 0x210f_705c_5c75_d245,
+    },
+    Vector {
+        name: "bytes_16",
+        writes: &[Write::Bytes(16)],
+        finish: 0x0582_9857_ffc7_87f0,
+    },
+    Vector {
+        name: "bytes_17",
+        writes: &[Write::Bytes(17)],
+        finish: 0x7bc7_1c04_9101_ffb6,
+    },
+    Vector {
+        name: "bytes_31",
+        writes: &[Write::Bytes(31)],
+        finish: 0x1137_636d_a59f_5f5e,
+    },
+    Vector {
+        name: "bytes_32",
+        writes: &[Write::Bytes(32)],
+        finish: 0x1bdd_1cb2_109f_888e,
+    },
+    Vector {
+        name: "bytes_33",
+        writes: &[Write::Bytes(33)],
+        finish: 0x37a0_2ac4_852e_4693,
+    },
+    Vector {
+        name: "bytes_55",
+        writes: &[Write::Bytes(55)],
+        finish: 0x28d1_3941_5894_a98a,
+    },
+    Vector {
+        name: "bytes_56",
+        writes: &[Write::Bytes(56)],
+        finish: 0x4c04_5cf7_0847_57ad,
+    },
+    Vector {
+        name: "bytes_57",
+        writes: &[Write::Bytes(57)],
+        finish: 0x1be0_2b09_02e5_465b,
+    },
+    Vector {
+        name: "bytes_63",
+        writes: &[Write::Bytes(63)],
+        finish: 0x45f8_1dff_e8d3_0e28,
+    },
+    Vector {
+        name: "bytes_64",
+        writes: &[Write::Bytes(64)],
+        finish: 0xa7b6_e5ad_2497_abc6,
+    },
+    Vector {
+        name: "bytes_65",
+        writes: &[Write::Bytes(65)],
+        finish: 0x4cdf_7d3c_c767_8378,
+    },
+    Vector {
+        name: "bytes_127",
+        writes: &[Write::Bytes(127)],
+        finish: 0xb714_0e28_cd09_2419,
+    },
+    Vector {
+        name: "bytes_128",
+        writes: &[Write::Bytes(128)],
+        finish: 0xfef6_c1de_a28c_54cc,
+    },
+    Vector {
+        name: "bytes_129",
+        writes: &[Write::Bytes(129)],
+        finish: 0xa7aa_1129_7b9a_e881,
+    },
+    Vector {
+        name: "bytes_255",
+        writes: &[Write::Bytes(255)],
+        finish: 0xe4a7_345a_c41b_24c9,
+    },
+    Vector {
+        name: "bytes_256",
+        writes: &[Write::Bytes(256)],
+        finish: 0xc4a6_7f59_2de0_c6c8,
+    },
+    Vector {
+        name: "bytes_1024",
+        writes: &[Write::Bytes(1024)],
+        finish: 0x1bbd_f666_6756_7e8d,
+    },
+    // Multi-write chains
+    Vector {
+        name: "bytes_5_7",
+        writes: &[Write::Bytes(5), Write::Bytes(7)],
+        finish: 0x4743_26c9_41f0_777d,
+    },
+    Vector {
+        name: "bytes_32_32_32",
+        writes: &[Write::Bytes(32), Write::Bytes(32), Write::Bytes(32)],
+        finish: 0xe2e1_f379_881f_b548,
+    },
+    Vector {
+        name: "bytes_0_1_0",
+        writes: &[Write::Bytes(0), Write::Bytes(1), Write::Bytes(0)],
+        finish: 0x4c46_89e9_a1aa_6c16,
+    },
+    // Integer writes
+    Vector {
+        name: "u8",
+        writes: &[Write::U8(0xab)],
+        finish: 0x44dd_82f1_f780_7d08,
+    },
+    Vector {
+        name: "u16",
+        writes: &[Write::U16(0xabcd)],
+        finish: 0x7539_7bdc_857f_7be3,
+    },
+    Vector {
+        name: "u32",
+        writes: &[Write::U32(0xdead_beef)],
+        finish: 0x30a1_0a85_c3d4_e0d9,
+    },
+    Vector {
+        name: "u64",
+        writes: &[Write::U64(0x0123_4567_89ab_cdef)],
+        finish: 0xdcd0_6162_b3a2_5ba8,
+    },
+    Vector {
+        name: "u128",
+        writes: &[Write::U128(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210)],
+        finish: 0xbc39_cc5e_c47f_6b29,
+    },
+    Vector {
+        name: "u64_x5",
+        writes: &[
+            Write::U64(1),
+            Write::U64(2),
+            Write::U64(3),
+            Write::U64(4),
+            Write::U64(5),
+        ],
+        finish: 0xb3d4_09cf_05ef_52c4,
+    },
+    Vector {
+        name: "u128_u128_u8",
+        writes: &[Write::U128(1), Write::U128(2), Write::U8(3)],
+        finish: 0x4fa6_db40_16ec_28b6,
+    },
+    // Integers around byte writes, like a length prefixed key
+    Vector {
+        name: "u64_bytes_32_u16",
+        writes: &[Write::U64(32), Write::Bytes(32), Write::U16(9)],
+        finish: 0xd0a1_bace_81f5_cbed,
+    },
+    Vector {
+        name: "u32_bytes_0_u32",
+        writes: &[Write::U32(1), Write::Bytes(0), Write::U32(2)],
+        finish: 0xd142_8db3_c492_50e9,
+    },
+];
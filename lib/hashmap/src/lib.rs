@@ -1,26 +1,52 @@
 #![no_std]
 use core::{
-    hash::Hash,
+    fmt::{self, Debug},
+    hash::{BuildHasher, Hash},
     ops::{Deref, DerefMut},
 };
 
-use hashbrown::HashMap as HHashMap;
+use hashbrown::{
+    hash_map::{IntoIter, Iter, IterMut},
+    HashMap as HHashMap,
+};
+
+use svm_hasher::{
+    BoundedBuildHasher, Fixed, PrehashedBuildHasher, SeededSvmBuildHasher, SvmBufferedBuildHasher,
+    SvmBuildHasher, SvmFastBuildHasher,
+};
+
+type SvmHashMap<K, V, S = SvmBuildHasher> = HHashMap<K, V, S>;
+
+/// A map for keys that are already uniformly distributed, like `Pubkey`s, it
+/// does not make any syscall to hash them.
+pub type PrehashedHashMap<K, V> = HashMap<K, V, PrehashedBuildHasher>;
 
-use svm_hasher::SvmBuildHasher;
+/// A map for keys that are not chosen by an attacker, it hashes them with
+/// `SvmFastHasher` without any syscall.
+pub type FastHashMap<K, V> = HashMap<K, V, SvmFastBuildHasher>;
 
-type SvmHashMap<K, V> = HHashMap<K, V, SvmBuildHasher>;
+/// A map keyed by `Pubkey`s, they are hashed with a single 32 byte slice and
+/// no length prefix, see `Fixed`.
+pub type PubkeyMap<V> = HashMap<Fixed<[u8; 32]>, V>;
 
-pub struct HashMap<K, V>(SvmHashMap<K, V>);
+/// A map that hashes every key with a single `sol_sha256` call, as long as
+/// what the key writes fits in the buffer of `SvmBufferedHasher`.
+pub type BufferedHashMap<K, V> = HashMap<K, V, SvmBufferedBuildHasher>;
 
-impl<K, V> Deref for HashMap<K, V> {
-    type Target = SvmHashMap<K, V>;
+/// A `hashbrown` map that hashes its keys with `S`, which is `SvmBuildHasher`
+/// unless another `BuildHasher` is given, so `HashMap<K, V>` is the map over
+/// `sol_sha256` and the other hashers share the same API.
+pub struct HashMap<K, V, S = SvmBuildHasher>(SvmHashMap<K, V, S>);
+
+impl<K, V, S> Deref for HashMap<K, V, S> {
+    type Target = SvmHashMap<K, V, S>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-impl<K, V> DerefMut for HashMap<K, V> {
+impl<K, V, S> DerefMut for HashMap<K, V, S> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
@@ -39,15 +65,58 @@ impl<K, V> HashMap<K, V> {
     }
 }
 
-impl<K, V> FromIterator<(K, V)> for HashMap<K, V>
+impl<K, V, S> HashMap<K, V, S> {
+    /// A map that hashes its keys with `hash_builder`, like a keyed, `Keccak`
+    /// or pass-through hasher.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self(HHashMap::with_hasher(hash_builder))
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self(HHashMap::with_capacity_and_hasher(capacity, hash_builder))
+    }
+}
+
+impl<K, V> HashMap<K, V, SeededSvmBuildHasher> {
+    pub fn with_seed(seed: [u8; 32]) -> Self {
+        Self(HHashMap::with_hasher(SeededSvmBuildHasher::new(seed)))
+    }
+
+    pub fn with_capacity_and_seed(capacity: usize, seed: [u8; 32]) -> Self {
+        Self(HHashMap::with_capacity_and_hasher(
+            capacity,
+            SeededSvmBuildHasher::new(seed),
+        ))
+    }
+}
+
+impl<K, V> HashMap<K, V, BoundedBuildHasher> {
+    /// A map that only hashes the length and the first and last `bound` bytes
+    /// of long keys, see `BoundedHasher`.
+    pub fn with_bound(bound: usize) -> Self {
+        Self(HHashMap::with_hasher(BoundedBuildHasher::new(bound)))
+    }
+
+    pub fn with_capacity_and_bound(capacity: usize, bound: usize) -> Self {
+        Self(HHashMap::with_capacity_and_hasher(
+            capacity,
+            BoundedBuildHasher::new(bound),
+        ))
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for HashMap<K, V, S>
 where
     K: Eq + Hash,
+    S: BuildHasher + Default,
 {
-    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> HashMap<K, V> {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
         Self(SvmHashMap::from_iter(iter))
     }
 }
 
+// Only for the default hasher, like `From` on the std `HashMap`, so that
+// `HashMap::from([...])` does not need the hasher spelled out
 impl<K, V, const N: usize> From<[(K, V); N]> for HashMap<K, V>
 where
     K: Eq + Hash,
@@ -56,3 +125,92 @@ where
         Self::from_iter(value)
     }
 }
+
+impl<K, V, S: Default> Default for HashMap<K, V, S> {
+    fn default() -> Self {
+        Self(SvmHashMap::default())
+    }
+}
+
+impl<K: Clone, V: Clone, S: Clone> Clone for HashMap<K, V, S> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+impl<K: Debug, V: Debug, S> Debug for HashMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<K, V, S> PartialEq for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K, V, S> Eq for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+impl<K, V, S> Extend<(K, V)> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a, K, V, S> Extend<(&'a K, &'a V)> for HashMap<K, V, S>
+where
+    K: Eq + Hash + Copy,
+    V: Copy,
+    S: BuildHasher,
+{
+    fn extend<T: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl<K, V, S> IntoIterator for HashMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a HashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut HashMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
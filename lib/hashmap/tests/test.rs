@@ -0,0 +1,157 @@
+use core::hash::{BuildHasherDefault, Hasher};
+
+use svm_hasher::{
+    Fixed, PrehashedBuildHasher, SeededSvmBuildHasher, SvmFastBuildHasher, SvmKeccakBuildHasher,
+};
+use svm_hashmap::{FastHashMap, HashMap, PrehashedHashMap, PubkeyMap};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct State {
+    balances: HashMap<[u8; 32], u64>,
+    nonce: u64,
+}
+
+#[test]
+fn test_derives() {
+    let mut state = State::default();
+    state.balances.insert([1; 32], 10);
+    state.balances.insert([2; 32], 20);
+
+    let cloned_state = state.clone();
+
+    assert_eq!(state, cloned_state);
+    assert_eq!(
+        format!("{:?}", state.balances),
+        format!("{:?}", *state.balances)
+    );
+
+    state.balances.insert([1; 32], 11);
+
+    assert_ne!(state, cloned_state);
+    assert_eq!(State::default(), State::default());
+}
+
+#[test]
+fn test_extend_and_into_iter() {
+    let mut map = HashMap::from([(1u64, 10u64)]);
+    map.extend([(2, 20), (3, 30)]);
+    map.extend([(&4, &40)]);
+
+    for (_, value) in &mut map {
+        *value += 1;
+    }
+
+    let mut total = 0;
+    for (key, value) in &map {
+        total += key + value;
+    }
+
+    assert_eq!(total, (1 + 2 + 3 + 4) + (11 + 21 + 31 + 41));
+
+    // The map is consumed
+    let mut pairs: Vec<(u64, u64)> = map.into_iter().collect();
+    pairs.sort();
+
+    assert_eq!(pairs, [(1, 11), (2, 21), (3, 31), (4, 41)]);
+}
+
+#[test]
+fn test_pubkey_map() {
+    // `new` and `from` are the ones of this crate's `HashMap`
+    let mut map = PubkeyMap::new();
+    map.insert(Fixed([1u8; 32]), 10u64);
+
+    assert_eq!(map, PubkeyMap::from([(Fixed([1; 32]), 10)]));
+    assert_eq!(map[&Fixed([1; 32])], 10);
+}
+
+#[test]
+fn test_with_hasher() {
+    let mut keccak_map = HashMap::with_hasher(SvmKeccakBuildHasher::default());
+    keccak_map.extend([([1u8; 32], 10u64), ([2; 32], 20)]);
+
+    let mut prehashed_map: HashMap<[u8; 32], u64, PrehashedBuildHasher> =
+        HashMap::with_capacity_and_hasher(2, Default::default());
+    prehashed_map.extend([([1u8; 32], 10u64), ([2; 32], 20)]);
+
+    let mut seeded_map = HashMap::with_hasher(<SeededSvmBuildHasher>::from_slot(7));
+    seeded_map.insert([1u8; 32], 10u64);
+
+    assert_eq!(keccak_map[&[1; 32]], 10);
+    assert_eq!(prehashed_map[&[2; 32]], 20);
+
+    // The alias is this crate's `HashMap`, not the `hashbrown` one
+    let prehashed_alias: PrehashedHashMap<[u8; 32], u64> = prehashed_map.clone();
+
+    assert_eq!(prehashed_alias, prehashed_map);
+
+    let fast_map: FastHashMap<[u8; 32], u64> =
+        [([1u8; 32], 10), ([2; 32], 20)].into_iter().collect();
+    let fast_alias: HashMap<[u8; 32], u64, SvmFastBuildHasher> = fast_map.clone();
+
+    assert_eq!(fast_alias, fast_map);
+    assert_eq!(seeded_map[&[1; 32]], 10);
+
+    // The two parameter form still uses `SvmBuildHasher`
+    let map: HashMap<[u8; 32], u64> = HashMap::from_iter([([1u8; 32], 10u64)]);
+
+    assert_eq!(map[&[1; 32]], 10);
+
+    // Any hasher with a `Default` can be collected into
+    let keccak_map: HashMap<[u8; 32], u64, SvmKeccakBuildHasher> =
+        [([1u8; 32], 10), ([2; 32], 20)].into_iter().collect();
+
+    assert_eq!(keccak_map[&[2; 32]], 20);
+}
+
+// Sends every key to the same bucket, like a test hasher that forces
+// collisions
+#[derive(Clone, Default)]
+struct CollidingHasher;
+
+impl Hasher for CollidingHasher {
+    fn write(&mut self, _: &[u8]) {}
+
+    fn finish(&self) -> u64 {
+        0
+    }
+}
+
+#[test]
+fn test_custom_hasher() {
+    let mut map: HashMap<u64, u64, BuildHasherDefault<CollidingHasher>> = HashMap::default();
+    map.extend((0..100).map(|key| (key, key * 2)));
+
+    assert_eq!(map.len(), 100);
+    assert!((0..100).all(|key| map[&key] == key * 2));
+    assert_eq!(map.remove(&50), Some(100));
+    assert!(!map.contains_key(&50));
+}
+
+#[test]
+fn test_with_seed() {
+    use core::hash::BuildHasher;
+
+    let mut map = HashMap::with_seed([9; 32]);
+    map.insert([1u8; 32], 10u64);
+
+    let mut other_map = HashMap::with_capacity_and_seed(4, [9; 32]);
+    other_map.insert([1u8; 32], 10u64);
+
+    assert!(other_map.capacity() >= 4);
+    assert_eq!(map[&[1; 32]], 10);
+    assert_eq!(map, other_map);
+
+    // The map hashes its keys with the seed it was given
+    assert_eq!(map.hasher().seed(), &[9; 32]);
+    assert_eq!(
+        map.hasher().hash_one([1u8; 32]),
+        <SeededSvmBuildHasher>::new([9; 32]).hash_one([1u8; 32])
+    );
+    assert_ne!(
+        map.hasher().hash_one([1u8; 32]),
+        HashMap::<[u8; 32], u64, SeededSvmBuildHasher>::with_seed([8; 32])
+            .hasher()
+            .hash_one([1u8; 32])
+    );
+}
@@ -1,26 +1,52 @@
 #![no_std]
 use core::{
-    hash::Hash,
-    ops::{Deref, DerefMut},
+    fmt::{self, Debug},
+    hash::{BuildHasher, Hash},
+    ops::{BitAnd, BitOr, BitXor, Deref, DerefMut, Sub},
 };
 
-use svm_hasher::SvmBuildHasher;
+use svm_hasher::{
+    BoundedBuildHasher, Fixed, PrehashedBuildHasher, SeededSvmBuildHasher, SvmBufferedBuildHasher,
+    SvmBuildHasher, SvmFastBuildHasher,
+};
+
+use hashbrown::{
+    hash_set::{IntoIter, Iter},
+    HashSet as HHashSet,
+};
+
+type SvmHashSet<K, S = SvmBuildHasher> = HHashSet<K, S>;
+
+/// A set for keys that are already uniformly distributed, like `Pubkey`s, it
+/// does not make any syscall to hash them.
+pub type PrehashedHashSet<K> = HashSet<K, PrehashedBuildHasher>;
+
+/// A set for keys that are not chosen by an attacker, it hashes them with
+/// `SvmFastHasher` without any syscall.
+pub type FastHashSet<K> = HashSet<K, SvmFastBuildHasher>;
 
-use hashbrown::HashSet as HHashSet;
+/// A set of `Pubkey`s, they are hashed with a single 32 byte slice and no
+/// length prefix, see `Fixed`.
+pub type PubkeySet = HashSet<Fixed<[u8; 32]>>;
 
-type SvmHashSet<K> = HHashSet<K, SvmBuildHasher>;
+/// A set that hashes every key with a single `sol_sha256` call, as long as
+/// what the key writes fits in the buffer of `SvmBufferedHasher`.
+pub type BufferedHashSet<K> = HashSet<K, SvmBufferedBuildHasher>;
 
-pub struct HashSet<K>(SvmHashSet<K>);
+/// A `hashbrown` set that hashes its keys with `S`, which is `SvmBuildHasher`
+/// unless another `BuildHasher` is given, so `HashSet<K>` is the set over
+/// `sol_sha256` and the other hashers share the same API.
+pub struct HashSet<K, S = SvmBuildHasher>(SvmHashSet<K, S>);
 
-impl<K> Deref for HashSet<K> {
-    type Target = SvmHashSet<K>;
+impl<K, S> Deref for HashSet<K, S> {
+    type Target = SvmHashSet<K, S>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-impl<K> DerefMut for HashSet<K> {
+impl<K, S> DerefMut for HashSet<K, S> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
@@ -39,15 +65,58 @@ impl<K> HashSet<K> {
     }
 }
 
-impl<K> FromIterator<K> for HashSet<K>
+impl<K, S> HashSet<K, S> {
+    /// A set that hashes its keys with `hash_builder`, like a keyed, `Keccak`
+    /// or pass-through hasher.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self(SvmHashSet::with_hasher(hash_builder))
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self(SvmHashSet::with_capacity_and_hasher(capacity, hash_builder))
+    }
+}
+
+impl<K> HashSet<K, SeededSvmBuildHasher> {
+    pub fn with_seed(seed: [u8; 32]) -> Self {
+        Self(SvmHashSet::with_hasher(SeededSvmBuildHasher::new(seed)))
+    }
+
+    pub fn with_capacity_and_seed(capacity: usize, seed: [u8; 32]) -> Self {
+        Self(SvmHashSet::with_capacity_and_hasher(
+            capacity,
+            SeededSvmBuildHasher::new(seed),
+        ))
+    }
+}
+
+impl<K> HashSet<K, BoundedBuildHasher> {
+    /// A set that only hashes the length and the first and last `bound` bytes
+    /// of long keys, see `BoundedHasher`.
+    pub fn with_bound(bound: usize) -> Self {
+        Self(SvmHashSet::with_hasher(BoundedBuildHasher::new(bound)))
+    }
+
+    pub fn with_capacity_and_bound(capacity: usize, bound: usize) -> Self {
+        Self(SvmHashSet::with_capacity_and_hasher(
+            capacity,
+            BoundedBuildHasher::new(bound),
+        ))
+    }
+}
+
+impl<K, S> FromIterator<K> for HashSet<K, S>
 where
     K: Eq + Hash,
+    S: BuildHasher + Default,
 {
     fn from_iter<T: IntoIterator<Item = K>>(iter: T) -> Self {
         Self(SvmHashSet::from_iter(iter))
     }
 }
 
+// Only for the default hasher, like `From` on the std `HashSet`, so that
+// `HashSet::from([...])` does not need the hasher spelled out
 impl<K, const N: usize> From<[K; N]> for HashSet<K>
 where
     K: Eq + Hash,
@@ -56,3 +125,128 @@ where
         Self::from_iter(value)
     }
 }
+
+impl<K, S: Default> Default for HashSet<K, S> {
+    fn default() -> Self {
+        Self(SvmHashSet::default())
+    }
+}
+
+impl<K: Clone, S: Clone> Clone for HashSet<K, S> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+impl<K: Debug, S> Debug for HashSet<K, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<K, S> PartialEq for HashSet<K, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K, S> Eq for HashSet<K, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+}
+
+impl<K, S> Extend<K> for HashSet<K, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend<T: IntoIterator<Item = K>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a, K, S> Extend<&'a K> for HashSet<K, S>
+where
+    K: Eq + Hash + Copy + 'a,
+    S: BuildHasher,
+{
+    fn extend<T: IntoIterator<Item = &'a K>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl<K, S> IntoIterator for HashSet<K, S> {
+    type Item = K;
+    type IntoIter = IntoIter<K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, K, S> IntoIterator for &'a HashSet<K, S> {
+    type Item = &'a K;
+    type IntoIter = Iter<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<K, S> BitOr<&HashSet<K, S>> for &HashSet<K, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    type Output = HashSet<K, S>;
+
+    fn bitor(self, rhs: &HashSet<K, S>) -> Self::Output {
+        HashSet(&self.0 | &rhs.0)
+    }
+}
+
+impl<K, S> BitAnd<&HashSet<K, S>> for &HashSet<K, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    type Output = HashSet<K, S>;
+
+    fn bitand(self, rhs: &HashSet<K, S>) -> Self::Output {
+        HashSet(&self.0 & &rhs.0)
+    }
+}
+
+impl<K, S> BitXor<&HashSet<K, S>> for &HashSet<K, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    type Output = HashSet<K, S>;
+
+    fn bitxor(self, rhs: &HashSet<K, S>) -> Self::Output {
+        HashSet(&self.0 ^ &rhs.0)
+    }
+}
+
+impl<K, S> Sub<&HashSet<K, S>> for &HashSet<K, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher + Default,
+{
+    type Output = HashSet<K, S>;
+
+    fn sub(self, rhs: &HashSet<K, S>) -> Self::Output {
+        HashSet(&self.0 - &rhs.0)
+    }
+}
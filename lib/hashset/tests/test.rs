@@ -0,0 +1,154 @@
+use core::hash::{BuildHasherDefault, Hasher};
+
+use svm_hasher::{
+    Fixed, PrehashedBuildHasher, SeededSvmBuildHasher, SvmFastBuildHasher, SvmKeccakBuildHasher,
+};
+use svm_hashset::{FastHashSet, HashSet, PrehashedHashSet, PubkeySet};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct State {
+    signers: HashSet<[u8; 32]>,
+    nonce: u64,
+}
+
+#[test]
+fn test_derives() {
+    let mut state = State::default();
+    state.signers.insert([1; 32]);
+    state.signers.insert([2; 32]);
+
+    let cloned_state = state.clone();
+
+    assert_eq!(state, cloned_state);
+    assert_eq!(
+        format!("{:?}", state.signers),
+        format!("{:?}", *state.signers)
+    );
+
+    state.signers.insert([3; 32]);
+
+    assert_ne!(state, cloned_state);
+    assert_eq!(State::default(), State::default());
+}
+
+#[test]
+fn test_extend_and_into_iter() {
+    let mut set = HashSet::from([1u64]);
+    set.extend([2, 3]);
+    set.extend([&4]);
+
+    assert_eq!((&set).into_iter().sum::<u64>(), 1 + 2 + 3 + 4);
+
+    // The set is consumed
+    let mut keys: Vec<u64> = set.into_iter().collect();
+    keys.sort();
+
+    assert_eq!(keys, [1, 2, 3, 4]);
+}
+
+#[test]
+fn test_set_operations() {
+    let first = HashSet::from([1u64, 2, 3]);
+    let second = HashSet::from([2u64, 3, 4]);
+
+    assert_eq!(&first | &second, HashSet::from([1, 2, 3, 4]));
+    assert_eq!(&first & &second, HashSet::from([2, 3]));
+    assert_eq!(&first ^ &second, HashSet::from([1, 4]));
+    assert_eq!(&first - &second, HashSet::from([1]));
+}
+
+#[test]
+fn test_pubkey_set() {
+    // `new` and `from` are the ones of this crate's `HashSet`
+    let mut set = PubkeySet::new();
+    set.insert(Fixed([1u8; 32]));
+
+    assert_eq!(set, PubkeySet::from([Fixed([1; 32])]));
+    assert!(set.contains(&Fixed([1; 32])));
+}
+
+#[test]
+fn test_with_hasher() {
+    let mut keccak_set = HashSet::with_hasher(SvmKeccakBuildHasher::default());
+    keccak_set.extend([[1u8; 32], [2; 32]]);
+
+    let mut prehashed_set: HashSet<[u8; 32], PrehashedBuildHasher> =
+        HashSet::with_capacity_and_hasher(2, Default::default());
+    prehashed_set.extend([[1u8; 32], [2; 32]]);
+
+    assert!(keccak_set.contains(&[1; 32]));
+    assert!(prehashed_set.contains(&[2; 32]));
+
+    // The alias is this crate's `HashSet`, not the `hashbrown` one
+    let prehashed_alias: PrehashedHashSet<[u8; 32]> = prehashed_set.clone();
+
+    assert_eq!(prehashed_alias, prehashed_set);
+
+    let fast_set: FastHashSet<[u8; 32]> = [[1u8; 32], [2; 32]].into_iter().collect();
+    let fast_alias: HashSet<[u8; 32], SvmFastBuildHasher> = fast_set.clone();
+
+    assert_eq!(fast_alias, fast_set);
+
+    // The one parameter form still uses `SvmBuildHasher`
+    let set: HashSet<[u8; 32]> = HashSet::from_iter([[1u8; 32]]);
+
+    assert!(set.contains(&[1; 32]));
+
+    // Any hasher with a `Default` can be collected into
+    let keccak_set: HashSet<[u8; 32], SvmKeccakBuildHasher> =
+        [[1u8; 32], [2; 32]].into_iter().collect();
+
+    assert!(keccak_set.contains(&[2; 32]));
+}
+
+// Sends every key to the same bucket, like a test hasher that forces
+// collisions
+#[derive(Clone, Default)]
+struct CollidingHasher;
+
+impl Hasher for CollidingHasher {
+    fn write(&mut self, _: &[u8]) {}
+
+    fn finish(&self) -> u64 {
+        0
+    }
+}
+
+#[test]
+fn test_custom_hasher() {
+    let mut set: HashSet<u64, BuildHasherDefault<CollidingHasher>> = HashSet::default();
+    set.extend(0..100);
+
+    assert_eq!(set.len(), 100);
+    assert!((0..100).all(|key| set.contains(&key)));
+    assert!(set.remove(&50));
+    assert!(!set.contains(&50));
+}
+
+#[test]
+fn test_with_seed() {
+    use core::hash::BuildHasher;
+
+    let mut set = HashSet::with_seed([9; 32]);
+    set.insert([1u8; 32]);
+
+    let mut other_set = HashSet::with_capacity_and_seed(4, [9; 32]);
+    other_set.insert([1u8; 32]);
+
+    assert!(other_set.capacity() >= 4);
+    assert!(set.contains(&[1; 32]));
+    assert_eq!(set, other_set);
+
+    // The set hashes its keys with the seed it was given
+    assert_eq!(set.hasher().seed(), &[9; 32]);
+    assert_eq!(
+        set.hasher().hash_one([1u8; 32]),
+        <SeededSvmBuildHasher>::new([9; 32]).hash_one([1u8; 32])
+    );
+    assert_ne!(
+        set.hasher().hash_one([1u8; 32]),
+        HashSet::<[u8; 32], SeededSvmBuildHasher>::with_seed([8; 32])
+            .hasher()
+            .hash_one([1u8; 32])
+    );
+}
@@ -3,6 +3,7 @@ use core::{
     hint,
     ops::{Add, Sub},
 };
+use digest::Digest;
 use pinocchio::{
     account_info::AccountInfo,
     entrypoint,
@@ -14,9 +15,18 @@ use pinocchio::{
 use pinocchio_log::log;
 use pinocchio_pubkey::declare_id;
 use std::collections::{hash_map::DefaultHasher, HashMap, HashSet};
-use svm_hasher::SvmSHA256Hasher;
-use svm_hashmap::HashMap as SvmHashMap;
-use svm_hashset::HashSet as SvmHashSet;
+#[cfg(feature = "blake3")]
+use svm_hasher::SvmBlake3Hasher;
+use svm_hasher::{
+    hashv, AdaptiveHasher, SvmFastHasher, SvmKeccakHasher, SvmSHA256Hasher, SvmSha256,
+    SvmSoftwareSHA256Hasher,
+};
+use svm_hashmap::{BufferedHashMap, FastHashMap, HashMap as SvmHashMap};
+use svm_hashset::{FastHashSet, HashSet as SvmHashSet};
+
+// The golden vectors of the host tests, so they are recomputed on-chain
+#[path = "../../lib/hasher/tests/vectors/mod.rs"]
+pub mod vectors;
 
 declare_id!("6q9CxFWZUyGnY3qWajwYHPLE5XMRhr7JYbfrxtnLB6Zw");
 
@@ -34,6 +44,16 @@ pub fn processor(
         3 => compare_cu_from_hash_set(&instruction_data[1..]),
         4 => compare_cu_from_hash_map(&instruction_data[1..]),
         5 => compare_cu_from_all(&instruction_data[1..]),
+        6 => compare_cu_from_keccak_hash(&instruction_data[1..]),
+        #[cfg(feature = "blake3")]
+        7 => compare_cu_from_blake3_hash(&instruction_data[1..]),
+        8 => compute_golden_vectors(),
+        9 => compare_cu_from_software_hash(&instruction_data[1..]),
+        10 => compare_cu_from_bounded_hash_map(&instruction_data[1..]),
+        11 => compare_cu_from_buffered_hash_map(&instruction_data[1..]),
+        12 => compute_iteration_order(&instruction_data[1..]),
+        13 => compute_map_costs(&instruction_data[1..]),
+        14 => compare_cu_from_sha256_digest(&instruction_data[1..]),
         _ => {
             return Err(ProgramError::InvalidInstructionData);
         }
@@ -90,6 +110,39 @@ pub fn test_hash_set(data: &[u8]) -> ProgramResult {
     hashset.insert(Pubkey::from(data_3));
     hashset.insert(Pubkey::from(data_4));
 
+    if !hashset.contains(&Pubkey::from(data_1))
+        || !hashset.contains(&Pubkey::from(data_2))
+        || !hashset.contains(&Pubkey::from(data_3))
+        || !hashset.contains(&Pubkey::from(data_4))
+    {
+        log!("Fail!!!");
+        return Err(Error::Fail.into());
+    }
+    // `with_seed`
+    let mut hashset = SvmHashSet::with_seed(data_1);
+
+    hashset.insert(Pubkey::from(data_1));
+    hashset.insert(Pubkey::from(data_2));
+    hashset.insert(Pubkey::from(data_3));
+    hashset.insert(Pubkey::from(data_4));
+
+    if !hashset.contains(&Pubkey::from(data_1))
+        || !hashset.contains(&Pubkey::from(data_2))
+        || !hashset.contains(&Pubkey::from(data_3))
+        || !hashset.contains(&Pubkey::from(data_4))
+    {
+        log!("Fail!!!");
+        return Err(Error::Fail.into());
+    }
+
+    // `with_bound`
+    let mut hashset = SvmHashSet::with_bound(8);
+
+    hashset.insert(Pubkey::from(data_1));
+    hashset.insert(Pubkey::from(data_2));
+    hashset.insert(Pubkey::from(data_3));
+    hashset.insert(Pubkey::from(data_4));
+
     if !hashset.contains(&Pubkey::from(data_1))
         || !hashset.contains(&Pubkey::from(data_2))
         || !hashset.contains(&Pubkey::from(data_3))
@@ -152,6 +205,39 @@ pub fn test_hash_map(data: &[u8]) -> ProgramResult {
     hashmap.insert(Pubkey::from(data_3), data_2);
     hashmap.insert(Pubkey::from(data_4), data_1);
 
+    if hashmap.get(&Pubkey::from(data_1)).unwrap().ne(&data_4)
+        || hashmap.get(&Pubkey::from(data_2)).unwrap().ne(&data_3)
+        || hashmap.get(&Pubkey::from(data_3)).unwrap().ne(&data_2)
+        || hashmap.get(&Pubkey::from(data_4)).unwrap().ne(&data_1)
+    {
+        log!("Fail!!!");
+        return Err(Error::Fail.into());
+    }
+    // `with_seed`
+    let mut hashmap = SvmHashMap::with_seed(data_1);
+
+    hashmap.insert(Pubkey::from(data_1), data_4);
+    hashmap.insert(Pubkey::from(data_2), data_3);
+    hashmap.insert(Pubkey::from(data_3), data_2);
+    hashmap.insert(Pubkey::from(data_4), data_1);
+
+    if hashmap.get(&Pubkey::from(data_1)).unwrap().ne(&data_4)
+        || hashmap.get(&Pubkey::from(data_2)).unwrap().ne(&data_3)
+        || hashmap.get(&Pubkey::from(data_3)).unwrap().ne(&data_2)
+        || hashmap.get(&Pubkey::from(data_4)).unwrap().ne(&data_1)
+    {
+        log!("Fail!!!");
+        return Err(Error::Fail.into());
+    }
+
+    // `with_bound`
+    let mut hashmap = SvmHashMap::with_bound(8);
+
+    hashmap.insert(Pubkey::from(data_1), data_4);
+    hashmap.insert(Pubkey::from(data_2), data_3);
+    hashmap.insert(Pubkey::from(data_3), data_2);
+    hashmap.insert(Pubkey::from(data_4), data_1);
+
     if hashmap.get(&Pubkey::from(data_1)).unwrap().ne(&data_4)
         || hashmap.get(&Pubkey::from(data_2)).unwrap().ne(&data_3)
         || hashmap.get(&Pubkey::from(data_3)).unwrap().ne(&data_2)
@@ -164,9 +250,219 @@ pub fn test_hash_map(data: &[u8]) -> ProgramResult {
     Ok(())
 }
 
+// Past the longest key the tests send, with its length prefix, so the
+// adaptive hasher always uses its mixer and the crossover can be measured
+const MIXER_THRESHOLD: usize = 264;
+
 pub fn compare_cu_from_hash(data: &[u8]) -> ProgramResult {
     let mut default_hasher = DefaultHasher::new();
     let mut custom_hasher = SvmSHA256Hasher::default();
+    let mut fast_hasher = SvmFastHasher::default();
+    let mut adaptive_hasher = <AdaptiveHasher>::default();
+    let mut mixer_hasher = AdaptiveHasher::<MIXER_THRESHOLD>::default();
+
+    let remaining_compute_units_1 = remaining_compute_units();
+
+    data.hash(&mut default_hasher);
+
+    let _ = hint::black_box(default_hasher.finish());
+
+    let remaining_compute_units_2 = remaining_compute_units();
+
+    data.hash(&mut custom_hasher);
+
+    let _ = hint::black_box(custom_hasher.finish());
+
+    let remaining_compute_units_3 = remaining_compute_units();
+
+    data.hash(&mut fast_hasher);
+
+    let _ = hint::black_box(fast_hasher.finish());
+
+    let remaining_compute_units_4 = remaining_compute_units();
+
+    data.hash(&mut adaptive_hasher);
+
+    let _ = hint::black_box(adaptive_hasher.finish());
+
+    let remaining_compute_units_5 = remaining_compute_units();
+
+    data.hash(&mut mixer_hasher);
+
+    let _ = hint::black_box(mixer_hasher.finish());
+
+    let remaining_compute_units_6 = remaining_compute_units();
+
+    let remaining_compute_units_compute_units = get_remaining_compute_units_compute_units();
+
+    let default_hasher_compute_units = remaining_compute_units_1
+        .sub(remaining_compute_units_2.add(remaining_compute_units_compute_units));
+
+    let custom_hasher_compute_units = remaining_compute_units_2
+        .sub(remaining_compute_units_3.add(remaining_compute_units_compute_units));
+
+    let fast_hasher_compute_units = remaining_compute_units_3
+        .sub(remaining_compute_units_4.add(remaining_compute_units_compute_units));
+
+    let adaptive_hasher_compute_units = remaining_compute_units_4
+        .sub(remaining_compute_units_5.add(remaining_compute_units_compute_units));
+
+    let mixer_hasher_compute_units = remaining_compute_units_5
+        .sub(remaining_compute_units_6.add(remaining_compute_units_compute_units));
+
+    let return_data: Vec<u8> = [
+        default_hasher_compute_units.to_le_bytes().as_ref(),
+        custom_hasher_compute_units.to_le_bytes().as_ref(),
+        fast_hasher_compute_units.to_le_bytes().as_ref(),
+        adaptive_hasher_compute_units.to_le_bytes().as_ref(),
+        mixer_hasher_compute_units.to_le_bytes().as_ref(),
+    ]
+    .concat();
+
+    unsafe {
+        sol_set_return_data(
+            return_data.as_ptr(),
+            u64::try_from(return_data.len()).unwrap(),
+        );
+    }
+
+    Ok(())
+}
+
+pub fn compare_cu_from_keccak_hash(data: &[u8]) -> ProgramResult {
+    let mut default_hasher = DefaultHasher::new();
+    let mut custom_hasher = SvmSHA256Hasher::default();
+    let mut keccak_hasher = SvmKeccakHasher::default();
+    let mut fast_hasher = SvmFastHasher::default();
+
+    let remaining_compute_units_1 = remaining_compute_units();
+
+    data.hash(&mut default_hasher);
+
+    let _ = hint::black_box(default_hasher.finish());
+
+    let remaining_compute_units_2 = remaining_compute_units();
+
+    data.hash(&mut custom_hasher);
+
+    let _ = hint::black_box(custom_hasher.finish());
+
+    let remaining_compute_units_3 = remaining_compute_units();
+
+    data.hash(&mut keccak_hasher);
+
+    let _ = hint::black_box(keccak_hasher.finish());
+
+    let remaining_compute_units_4 = remaining_compute_units();
+
+    data.hash(&mut fast_hasher);
+
+    let _ = hint::black_box(fast_hasher.finish());
+
+    let remaining_compute_units_5 = remaining_compute_units();
+
+    let remaining_compute_units_compute_units = get_remaining_compute_units_compute_units();
+
+    let default_hasher_compute_units = remaining_compute_units_1
+        .sub(remaining_compute_units_2.add(remaining_compute_units_compute_units));
+
+    let custom_hasher_compute_units = remaining_compute_units_2
+        .sub(remaining_compute_units_3.add(remaining_compute_units_compute_units));
+
+    let keccak_hasher_compute_units = remaining_compute_units_3
+        .sub(remaining_compute_units_4.add(remaining_compute_units_compute_units));
+
+    let fast_hasher_compute_units = remaining_compute_units_4
+        .sub(remaining_compute_units_5.add(remaining_compute_units_compute_units));
+
+    let return_data: Vec<u8> = [
+        default_hasher_compute_units.to_le_bytes().as_ref(),
+        custom_hasher_compute_units.to_le_bytes().as_ref(),
+        keccak_hasher_compute_units.to_le_bytes().as_ref(),
+        fast_hasher_compute_units.to_le_bytes().as_ref(),
+    ]
+    .concat();
+
+    unsafe {
+        sol_set_return_data(
+            return_data.as_ptr(),
+            u64::try_from(return_data.len()).unwrap(),
+        );
+    }
+
+    Ok(())
+}
+
+pub fn compare_cu_from_software_hash(data: &[u8]) -> ProgramResult {
+    let mut default_hasher = DefaultHasher::new();
+    let mut custom_hasher = SvmSHA256Hasher::default();
+    let mut software_hasher = SvmSoftwareSHA256Hasher::default();
+    let mut fast_hasher = SvmFastHasher::default();
+
+    let remaining_compute_units_1 = remaining_compute_units();
+
+    data.hash(&mut default_hasher);
+
+    let _ = hint::black_box(default_hasher.finish());
+
+    let remaining_compute_units_2 = remaining_compute_units();
+
+    data.hash(&mut custom_hasher);
+
+    let _ = hint::black_box(custom_hasher.finish());
+
+    let remaining_compute_units_3 = remaining_compute_units();
+
+    data.hash(&mut software_hasher);
+
+    let _ = hint::black_box(software_hasher.finish());
+
+    let remaining_compute_units_4 = remaining_compute_units();
+
+    data.hash(&mut fast_hasher);
+
+    let _ = hint::black_box(fast_hasher.finish());
+
+    let remaining_compute_units_5 = remaining_compute_units();
+
+    let remaining_compute_units_compute_units = get_remaining_compute_units_compute_units();
+
+    let default_hasher_compute_units = remaining_compute_units_1
+        .sub(remaining_compute_units_2.add(remaining_compute_units_compute_units));
+
+    let custom_hasher_compute_units = remaining_compute_units_2
+        .sub(remaining_compute_units_3.add(remaining_compute_units_compute_units));
+
+    let software_hasher_compute_units = remaining_compute_units_3
+        .sub(remaining_compute_units_4.add(remaining_compute_units_compute_units));
+
+    let fast_hasher_compute_units = remaining_compute_units_4
+        .sub(remaining_compute_units_5.add(remaining_compute_units_compute_units));
+
+    let return_data: Vec<u8> = [
+        default_hasher_compute_units.to_le_bytes().as_ref(),
+        custom_hasher_compute_units.to_le_bytes().as_ref(),
+        software_hasher_compute_units.to_le_bytes().as_ref(),
+        fast_hasher_compute_units.to_le_bytes().as_ref(),
+    ]
+    .concat();
+
+    unsafe {
+        sol_set_return_data(
+            return_data.as_ptr(),
+            u64::try_from(return_data.len()).unwrap(),
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "blake3")]
+pub fn compare_cu_from_blake3_hash(data: &[u8]) -> ProgramResult {
+    let mut default_hasher = DefaultHasher::new();
+    let mut custom_hasher = SvmSHA256Hasher::default();
+    let mut blake3_hasher = SvmBlake3Hasher::default();
+    let mut fast_hasher = SvmFastHasher::default();
 
     let remaining_compute_units_1 = remaining_compute_units();
 
@@ -182,6 +478,18 @@ pub fn compare_cu_from_hash(data: &[u8]) -> ProgramResult {
 
     let remaining_compute_units_3 = remaining_compute_units();
 
+    data.hash(&mut blake3_hasher);
+
+    let _ = hint::black_box(blake3_hasher.finish());
+
+    let remaining_compute_units_4 = remaining_compute_units();
+
+    data.hash(&mut fast_hasher);
+
+    let _ = hint::black_box(fast_hasher.finish());
+
+    let remaining_compute_units_5 = remaining_compute_units();
+
     let remaining_compute_units_compute_units = get_remaining_compute_units_compute_units();
 
     let default_hasher_compute_units = remaining_compute_units_1
@@ -190,9 +498,56 @@ pub fn compare_cu_from_hash(data: &[u8]) -> ProgramResult {
     let custom_hasher_compute_units = remaining_compute_units_2
         .sub(remaining_compute_units_3.add(remaining_compute_units_compute_units));
 
+    let blake3_hasher_compute_units = remaining_compute_units_3
+        .sub(remaining_compute_units_4.add(remaining_compute_units_compute_units));
+
+    let fast_hasher_compute_units = remaining_compute_units_4
+        .sub(remaining_compute_units_5.add(remaining_compute_units_compute_units));
+
     let return_data: Vec<u8> = [
         default_hasher_compute_units.to_le_bytes().as_ref(),
         custom_hasher_compute_units.to_le_bytes().as_ref(),
+        blake3_hasher_compute_units.to_le_bytes().as_ref(),
+        fast_hasher_compute_units.to_le_bytes().as_ref(),
+    ]
+    .concat();
+
+    unsafe {
+        sol_set_return_data(
+            return_data.as_ptr(),
+            u64::try_from(return_data.len()).unwrap(),
+        );
+    }
+
+    Ok(())
+}
+
+// Returns the compute units of `SvmSha256` and of a single `hashv` call over
+// `data`, followed by both digests
+pub fn compare_cu_from_sha256_digest(data: &[u8]) -> ProgramResult {
+    let remaining_compute_units_1 = remaining_compute_units();
+
+    let digest = hint::black_box(SvmSha256::digest(data));
+
+    let remaining_compute_units_2 = remaining_compute_units();
+
+    let hashv_digest = hint::black_box(hashv(&[data]));
+
+    let remaining_compute_units_3 = remaining_compute_units();
+
+    let remaining_compute_units_compute_units = get_remaining_compute_units_compute_units();
+
+    let digest_compute_units = remaining_compute_units_1
+        .sub(remaining_compute_units_2.add(remaining_compute_units_compute_units));
+
+    let hashv_compute_units = remaining_compute_units_2
+        .sub(remaining_compute_units_3.add(remaining_compute_units_compute_units));
+
+    let return_data: Vec<u8> = [
+        digest_compute_units.to_le_bytes().as_ref(),
+        hashv_compute_units.to_le_bytes().as_ref(),
+        digest.as_slice(),
+        hashv_digest.as_ref(),
     ]
     .concat();
 
@@ -206,6 +561,50 @@ pub fn compare_cu_from_hash(data: &[u8]) -> ProgramResult {
     Ok(())
 }
 
+pub fn compute_golden_vectors() -> ProgramResult {
+    let mut return_data = Vec::with_capacity(vectors::VECTORS.len() * 8);
+
+    for vector in vectors::VECTORS {
+        let mut hasher = SvmSHA256Hasher::default();
+        vector.apply(&mut hasher);
+
+        return_data.extend_from_slice(hasher.finish().to_le_bytes().as_ref());
+    }
+
+    unsafe {
+        sol_set_return_data(
+            return_data.as_ptr(),
+            u64::try_from(return_data.len()).unwrap(),
+        );
+    }
+
+    Ok(())
+}
+
+// Inserts the 32 byte keys in `data` in order, each with its index as value,
+// and returns the values in the order the map iterates over them
+pub fn compute_iteration_order(data: &[u8]) -> ProgramResult {
+    let mut map = SvmHashMap::new();
+
+    for (index, key) in data.chunks_exact(32).enumerate() {
+        map.insert(
+            <[u8; 32]>::try_from(key).unwrap(),
+            u8::try_from(index).unwrap(),
+        );
+    }
+
+    let return_data: Vec<u8> = map.values().copied().collect();
+
+    unsafe {
+        sol_set_return_data(
+            return_data.as_ptr(),
+            u64::try_from(return_data.len()).unwrap(),
+        );
+    }
+
+    Ok(())
+}
+
 pub fn compare_cu_from_hash_set(data: &[u8]) -> ProgramResult {
     let data = into_slices(data);
 
@@ -215,6 +614,9 @@ pub fn compare_cu_from_hash_set(data: &[u8]) -> ProgramResult {
     let mut custom_hasher_set = SvmHashSet::with_capacity(data.len());
     custom_hasher_set.extend(data.iter().map(|(first, _)| *first).into_iter());
 
+    let mut fast_hasher_set = FastHashSet::with_capacity_and_hasher(data.len(), Default::default());
+    fast_hasher_set.extend(data.iter().map(|(first, _)| *first));
+
     let mut return_data = Vec::with_capacity(data.len());
 
     for data in data {
@@ -228,6 +630,10 @@ pub fn compare_cu_from_hash_set(data: &[u8]) -> ProgramResult {
 
         let remaining_compute_units_3 = remaining_compute_units();
 
+        let _ = hint::black_box(fast_hasher_set.contains(data.0));
+
+        let remaining_compute_units_4 = remaining_compute_units();
+
         let remaining_compute_units_compute_units = get_remaining_compute_units_compute_units();
 
         let default_hasher_compute_units = remaining_compute_units_1
@@ -236,8 +642,12 @@ pub fn compare_cu_from_hash_set(data: &[u8]) -> ProgramResult {
         let custom_hasher_compute_units = remaining_compute_units_2
             .sub(remaining_compute_units_3.add(remaining_compute_units_compute_units));
 
+        let fast_hasher_compute_units = remaining_compute_units_3
+            .sub(remaining_compute_units_4.add(remaining_compute_units_compute_units));
+
         return_data.extend_from_slice(default_hasher_compute_units.to_le_bytes().as_ref());
         return_data.extend_from_slice(custom_hasher_compute_units.to_le_bytes().as_ref());
+        return_data.extend_from_slice(fast_hasher_compute_units.to_le_bytes().as_ref());
     }
 
     unsafe {
@@ -267,6 +677,9 @@ pub fn compare_cu_from_hash_map(data: &[u8]) -> ProgramResult {
             .into_iter(),
     );
 
+    let mut fast_hasher_map = FastHashMap::with_capacity_and_hasher(data.len(), Default::default());
+    fast_hasher_map.extend(data.iter().map(|(first, second)| (*first, *second)));
+
     let mut return_data = Vec::with_capacity(data.len());
 
     for data in data {
@@ -280,6 +693,10 @@ pub fn compare_cu_from_hash_map(data: &[u8]) -> ProgramResult {
 
         let remaining_compute_units_3 = remaining_compute_units();
 
+        let _ = hint::black_box(fast_hasher_map[data.0]);
+
+        let remaining_compute_units_4 = remaining_compute_units();
+
         let remaining_compute_units_compute_units = get_remaining_compute_units_compute_units();
 
         let default_hasher_compute_units = remaining_compute_units_1
@@ -288,8 +705,12 @@ pub fn compare_cu_from_hash_map(data: &[u8]) -> ProgramResult {
         let custom_hasher_compute_units = remaining_compute_units_2
             .sub(remaining_compute_units_3.add(remaining_compute_units_compute_units));
 
+        let fast_hasher_compute_units = remaining_compute_units_3
+            .sub(remaining_compute_units_4.add(remaining_compute_units_compute_units));
+
         return_data.extend_from_slice(default_hasher_compute_units.to_le_bytes().as_ref());
         return_data.extend_from_slice(custom_hasher_compute_units.to_le_bytes().as_ref());
+        return_data.extend_from_slice(fast_hasher_compute_units.to_le_bytes().as_ref());
     }
 
     unsafe {
@@ -302,11 +723,148 @@ pub fn compare_cu_from_hash_map(data: &[u8]) -> ProgramResult {
     Ok(())
 }
 
+// `data` is a key length followed by keys of that length, returns the compute
+// units of inserting each key and then of looking it up, to check the
+// estimates in `svm_hasher::cost` against
+pub fn compute_map_costs(data: &[u8]) -> ProgramResult {
+    let keys: Vec<&[u8]> = data[1..].chunks_exact(usize::from(data[0])).collect();
+
+    // With enough capacity no insert grows the table
+    let mut map = SvmHashMap::with_capacity(keys.len());
+
+    let mut return_data = Vec::with_capacity(keys.len() * 16);
+
+    for key in keys.iter() {
+        let remaining_compute_units_1 = remaining_compute_units();
+
+        let _ = hint::black_box(map.insert(*key, ()));
+
+        let remaining_compute_units_2 = remaining_compute_units();
+
+        let insert_compute_units = remaining_compute_units_1
+            .sub(remaining_compute_units_2.add(get_remaining_compute_units_compute_units()));
+
+        return_data.extend_from_slice(insert_compute_units.to_le_bytes().as_ref());
+    }
+
+    for key in keys.iter() {
+        let remaining_compute_units_1 = remaining_compute_units();
+
+        let _ = hint::black_box(map.contains_key(key));
+
+        let remaining_compute_units_2 = remaining_compute_units();
+
+        let lookup_compute_units = remaining_compute_units_1
+            .sub(remaining_compute_units_2.add(get_remaining_compute_units_compute_units()));
+
+        return_data.extend_from_slice(lookup_compute_units.to_le_bytes().as_ref());
+    }
+
+    unsafe {
+        sol_set_return_data(
+            return_data.as_ptr(),
+            u64::try_from(return_data.len()).unwrap(),
+        );
+    }
+
+    Ok(())
+}
+
+// Enough to tell most keys apart, while a lookup stays at a couple of syscalls
+const BOUND: usize = 32;
+
+pub fn compare_cu_from_bounded_hash_map(data: &[u8]) -> ProgramResult {
+    let mut custom_hasher_map = SvmHashMap::with_capacity(1);
+    custom_hasher_map.insert(data, ());
+
+    let mut bounded_hasher_map = SvmHashMap::with_capacity_and_bound(1, BOUND);
+    bounded_hasher_map.insert(data, ());
+
+    let remaining_compute_units_1 = remaining_compute_units();
+
+    let _ = hint::black_box(custom_hasher_map.contains_key(data));
+
+    let remaining_compute_units_2 = remaining_compute_units();
+
+    let _ = hint::black_box(bounded_hasher_map.contains_key(data));
+
+    let remaining_compute_units_3 = remaining_compute_units();
+
+    let remaining_compute_units_compute_units = get_remaining_compute_units_compute_units();
+
+    let custom_hasher_compute_units = remaining_compute_units_1
+        .sub(remaining_compute_units_2.add(remaining_compute_units_compute_units));
+
+    let bounded_hasher_compute_units = remaining_compute_units_2
+        .sub(remaining_compute_units_3.add(remaining_compute_units_compute_units));
+
+    let return_data: Vec<u8> = [
+        custom_hasher_compute_units.to_le_bytes().as_ref(),
+        bounded_hasher_compute_units.to_le_bytes().as_ref(),
+    ]
+    .concat();
+
+    unsafe {
+        sol_set_return_data(
+            return_data.as_ptr(),
+            u64::try_from(return_data.len()).unwrap(),
+        );
+    }
+
+    Ok(())
+}
+
+pub fn compare_cu_from_buffered_hash_map(data: &[u8]) -> ProgramResult {
+    // A key that writes two slices, `SvmHasher` makes a syscall for each of
+    // them while `SvmBufferedHasher` makes one while they fit in its buffer
+    let key = data.split_at(data.len() / 2);
+
+    let mut custom_hasher_map = SvmHashMap::with_capacity(1);
+    custom_hasher_map.insert(key, ());
+
+    let mut buffered_hasher_map = BufferedHashMap::with_capacity_and_hasher(1, Default::default());
+    buffered_hasher_map.insert(key, ());
+
+    let remaining_compute_units_1 = remaining_compute_units();
+
+    let _ = hint::black_box(custom_hasher_map.contains_key(&key));
+
+    let remaining_compute_units_2 = remaining_compute_units();
+
+    let _ = hint::black_box(buffered_hasher_map.contains_key(&key));
+
+    let remaining_compute_units_3 = remaining_compute_units();
+
+    let remaining_compute_units_compute_units = get_remaining_compute_units_compute_units();
+
+    let custom_hasher_compute_units = remaining_compute_units_1
+        .sub(remaining_compute_units_2.add(remaining_compute_units_compute_units));
+
+    let buffered_hasher_compute_units = remaining_compute_units_2
+        .sub(remaining_compute_units_3.add(remaining_compute_units_compute_units));
+
+    let return_data: Vec<u8> = [
+        custom_hasher_compute_units.to_le_bytes().as_ref(),
+        buffered_hasher_compute_units.to_le_bytes().as_ref(),
+    ]
+    .concat();
+
+    unsafe {
+        sol_set_return_data(
+            return_data.as_ptr(),
+            u64::try_from(return_data.len()).unwrap(),
+        );
+    }
+
+    Ok(())
+}
+
 pub fn compare_cu_from_all(data: &[u8]) -> ProgramResult {
     let data = into_slices(data);
 
     let mut default_hasher = DefaultHasher::new();
     let mut custom_hasher = SvmSHA256Hasher::default();
+    let mut fast_hasher = SvmFastHasher::default();
 
     let mut default_hasher_set = HashSet::with_capacity(data.len());
     default_hasher_set.extend(data.iter().map(|(first, _)| *first).into_iter());
@@ -314,6 +872,9 @@ pub fn compare_cu_from_all(data: &[u8]) -> ProgramResult {
     let mut custom_hasher_set = SvmHashSet::with_capacity(data.len());
     custom_hasher_set.extend(data.iter().map(|(first, _)| *first).into_iter());
 
+    let mut fast_hasher_set = FastHashSet::with_capacity_and_hasher(data.len(), Default::default());
+    fast_hasher_set.extend(data.iter().map(|(first, _)| *first));
+
     let mut default_hasher_map = HashMap::with_capacity(data.len());
     default_hasher_map.extend(
         data.iter()
@@ -328,7 +889,10 @@ pub fn compare_cu_from_all(data: &[u8]) -> ProgramResult {
             .into_iter(),
     );
 
-    let mut return_data = Vec::with_capacity(data.len() * 8 * 6);
+    let mut fast_hasher_map = FastHashMap::with_capacity_and_hasher(data.len(), Default::default());
+    fast_hasher_map.extend(data.iter().map(|(first, second)| (*first, *second)));
+
+    let mut return_data = Vec::with_capacity(data.len() * 8 * 9);
 
     for data in data {
         // hasher
@@ -346,6 +910,12 @@ pub fn compare_cu_from_all(data: &[u8]) -> ProgramResult {
 
         let remaining_compute_units_3 = remaining_compute_units();
 
+        data.hash(&mut fast_hasher);
+
+        let _ = hint::black_box(fast_hasher.finish());
+
+        let remaining_compute_units_4 = remaining_compute_units();
+
         let remaining_compute_units_compute_units = get_remaining_compute_units_compute_units();
 
         let default_hasher_compute_units = remaining_compute_units_1
@@ -354,8 +924,12 @@ pub fn compare_cu_from_all(data: &[u8]) -> ProgramResult {
         let custom_hasher_compute_units = remaining_compute_units_2
             .sub(remaining_compute_units_3.add(remaining_compute_units_compute_units));
 
+        let fast_hasher_compute_units = remaining_compute_units_3
+            .sub(remaining_compute_units_4.add(remaining_compute_units_compute_units));
+
         return_data.extend_from_slice(default_hasher_compute_units.to_le_bytes().as_ref());
         return_data.extend_from_slice(custom_hasher_compute_units.to_le_bytes().as_ref());
+        return_data.extend_from_slice(fast_hasher_compute_units.to_le_bytes().as_ref());
 
         // hashset
         let remaining_compute_units_1 = remaining_compute_units();
@@ -368,6 +942,10 @@ pub fn compare_cu_from_all(data: &[u8]) -> ProgramResult {
 
         let remaining_compute_units_3 = remaining_compute_units();
 
+        let _ = hint::black_box(fast_hasher_set.contains(data.0));
+
+        let remaining_compute_units_4 = remaining_compute_units();
+
         let remaining_compute_units_compute_units = get_remaining_compute_units_compute_units();
 
         let default_hasher_compute_units = remaining_compute_units_1
@@ -376,8 +954,12 @@ pub fn compare_cu_from_all(data: &[u8]) -> ProgramResult {
         let custom_hasher_compute_units = remaining_compute_units_2
             .sub(remaining_compute_units_3.add(remaining_compute_units_compute_units));
 
+        let fast_hasher_compute_units = remaining_compute_units_3
+            .sub(remaining_compute_units_4.add(remaining_compute_units_compute_units));
+
         return_data.extend_from_slice(default_hasher_compute_units.to_le_bytes().as_ref());
         return_data.extend_from_slice(custom_hasher_compute_units.to_le_bytes().as_ref());
+        return_data.extend_from_slice(fast_hasher_compute_units.to_le_bytes().as_ref());
 
         // hashmap
         let remaining_compute_units_1 = remaining_compute_units();
@@ -390,6 +972,10 @@ pub fn compare_cu_from_all(data: &[u8]) -> ProgramResult {
 
         let remaining_compute_units_3 = remaining_compute_units();
 
+        let _ = hint::black_box(fast_hasher_map[data.0]);
+
+        let remaining_compute_units_4 = remaining_compute_units();
+
         let remaining_compute_units_compute_units = get_remaining_compute_units_compute_units();
 
         let default_hasher_compute_units = remaining_compute_units_1
@@ -398,8 +984,12 @@ pub fn compare_cu_from_all(data: &[u8]) -> ProgramResult {
         let custom_hasher_compute_units = remaining_compute_units_2
             .sub(remaining_compute_units_3.add(remaining_compute_units_compute_units));
 
+        let fast_hasher_compute_units = remaining_compute_units_3
+            .sub(remaining_compute_units_4.add(remaining_compute_units_compute_units));
+
         return_data.extend_from_slice(default_hasher_compute_units.to_le_bytes().as_ref());
         return_data.extend_from_slice(custom_hasher_compute_units.to_le_bytes().as_ref());
+        return_data.extend_from_slice(fast_hasher_compute_units.to_le_bytes().as_ref());
     }
 
     unsafe {
@@ -3,7 +3,9 @@ use solana_instruction::Instruction;
 
 use solana_address::Address;
 
-use test_program::ID as TEST_PROGRAM_ID;
+use svm_hash_client::MapLayout;
+
+use test_program::{vectors::VECTORS, ID as TEST_PROGRAM_ID};
 
 use mollusk_svm::{result::Check, Mollusk};
 
@@ -42,6 +44,180 @@ pub fn test_hash_set() {
     mollusk.process_and_validate_instruction(&test_hash_set_instruction, &[], &[Check::success()]);
 }
 
+#[test]
+pub fn test_golden_vectors() {
+    let program_id = Address::from(TEST_PROGRAM_ID);
+    let mollusk = Mollusk::new(&program_id, "test_program");
+
+    let instruction = Instruction {
+        program_id,
+        accounts: vec![],
+        data: vec![8],
+    };
+
+    let result = mollusk.process_and_validate_instruction(&instruction, &[], &[Check::success()]);
+
+    assert_eq!(result.return_data.len(), VECTORS.len() * 8);
+
+    for (vector, finish) in VECTORS.iter().zip(result.return_data.chunks_exact(8)) {
+        assert_eq!(
+            u64::from_le_bytes(finish.try_into().unwrap()),
+            vector.finish,
+            "{}",
+            vector.name
+        );
+    }
+}
+
+#[test]
+pub fn test_iteration_order() {
+    let program_id = Address::from(TEST_PROGRAM_ID);
+    let mollusk = Mollusk::new(&program_id, "test_program");
+
+    for key_count in [1, 7, 10, 100, 255] {
+        let keys = random_input_data_with_len(key_count * 32, &mut rand::rng());
+
+        let instruction = Instruction {
+            program_id,
+            accounts: vec![],
+            data: std::iter::once(12u8).chain(keys.iter().copied()).collect(),
+        };
+
+        let result =
+            mollusk.process_and_validate_instruction(&instruction, &[], &[Check::success()]);
+
+        let keys: Vec<[u8; 32]> = keys
+            .chunks_exact(32)
+            .map(|key| key.try_into().unwrap())
+            .collect();
+
+        let mut layout = MapLayout::<[u8; 32], u8>::new();
+        layout.extend(keys.iter().copied());
+
+        let expected: Vec<u8> = layout
+            .iter()
+            .map(|key| keys.iter().position(|inserted| inserted == key).unwrap() as u8)
+            .collect();
+
+        assert_eq!(result.return_data, expected, "{} keys", key_count);
+    }
+}
+
+// How far, in compute units, a measured map insert or lookup may be from the
+// estimate of `svm_hasher::cost`
+const COST_TOLERANCE: u64 = 50;
+
+// Ignored until `LOOKUP_OVERHEAD` and `INSERT_OVERHEAD` are set from its output
+#[test]
+#[ignore]
+pub fn test_map_costs() {
+    let program_id = Address::from(TEST_PROGRAM_ID);
+    let mollusk = Mollusk::new(&program_id, "test_program");
+
+    // 32 keys of each length stay under the 1024 bytes of return data
+    let key_count = 32;
+
+    for key_len in [8, 32, 64, 100, 128, 200, 255] {
+        let keys = random_input_data_with_len(key_count * key_len, &mut rand::rng());
+
+        let instruction = Instruction {
+            program_id,
+            accounts: vec![],
+            data: [13u8, key_len as u8]
+                .into_iter()
+                .chain(keys.iter().copied())
+                .collect(),
+        };
+
+        let result =
+            mollusk.process_and_validate_instruction(&instruction, &[], &[Check::success()]);
+
+        let (inserts, lookups) = result.return_data.split_at(key_count * 8);
+
+        for ((key, insert), lookup) in keys
+            .chunks_exact(key_len)
+            .zip(inserts.chunks_exact(8))
+            .zip(lookups.chunks_exact(8))
+        {
+            let insert = u64::from_le_bytes(insert.try_into().unwrap());
+            let lookup = u64::from_le_bytes(lookup.try_into().unwrap());
+
+            println!(
+                "Key length: {} \n\
+                Insert: {} (estimate {}) | Lookup: {} (estimate {}) \n",
+                key_len,
+                insert,
+                svm_hasher::cost::map_insert_cost(&key),
+                lookup,
+                svm_hasher::cost::map_lookup_cost(&key)
+            );
+
+            assert!(
+                insert.abs_diff(svm_hasher::cost::map_insert_cost(&key)) <= COST_TOLERANCE,
+                "insert of a {} byte key: measured {}, estimated {}",
+                key_len,
+                insert,
+                svm_hasher::cost::map_insert_cost(&key)
+            );
+            assert!(
+                lookup.abs_diff(svm_hasher::cost::map_lookup_cost(&key)) <= COST_TOLERANCE,
+                "lookup of a {} byte key: measured {}, estimated {}",
+                key_len,
+                lookup,
+                svm_hasher::cost::map_lookup_cost(&key)
+            );
+        }
+    }
+}
+
+#[test]
+pub fn test_sha256_digest() {
+    use sha2::{Digest, Sha256};
+
+    let program_id = Address::from(TEST_PROGRAM_ID);
+    let mollusk = Mollusk::new(&program_id, "test_program");
+
+    for data_len in [1024, 4096, 8192] {
+        let data = random_input_data_with_len(data_len, &mut rand::rng());
+
+        let instruction = Instruction {
+            program_id,
+            accounts: vec![],
+            data: std::iter::once(14u8).chain(data.iter().copied()).collect(),
+        };
+
+        let result =
+            mollusk.process_and_validate_instruction(&instruction, &[], &[Check::success()]);
+
+        let (digest_compute_units, hashv_compute_units) = (
+            u64::from_le_bytes(result.return_data[..8].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[8..16].try_into().unwrap()),
+        );
+
+        println!(
+            "Data length: {} \n\
+            SvmSha256: {} | hashv: {} \n",
+            data_len, digest_compute_units, hashv_compute_units
+        );
+
+        assert_eq!(
+            &result.return_data[16..48],
+            Sha256::digest(&data).as_slice()
+        );
+        assert_eq!(&result.return_data[48..], Sha256::digest(&data).as_slice());
+
+        // Copying the input into the buffer is cheap, a second syscall would
+        // cost at least its base cost on top of the single `hashv` call
+        assert!(
+            digest_compute_units < hashv_compute_units + svm_hasher::cost::SHA256_BASE_COST,
+            "{} bytes: SvmSha256 {}, hashv {}",
+            data_len,
+            digest_compute_units,
+            hashv_compute_units
+        );
+    }
+}
+
 const INPUT_COUNT: usize = 256;
 const INNER_INPUT_COUNT: usize = 1;
 const LOWER_BOUND: usize = 8;
@@ -80,16 +256,187 @@ fn test_compare_cu_from_hash() {
         let result =
             mollusk.process_and_validate_instruction(&instruction, &[], &[Check::success()]);
 
-        let (siphasher_compute_units, custom_compute_units) = (
+        let (
+            siphasher_compute_units,
+            custom_compute_units,
+            fast_compute_units,
+            adaptive_compute_units,
+        ) = (
             u64::from_le_bytes(result.return_data[..8].try_into().unwrap()),
-            u64::from_le_bytes(result.return_data[8..].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[8..16].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[16..24].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[24..32].try_into().unwrap()),
+        );
+
+        output.push(format!(
+            "Run {}: \n\
+            Data length: {} \n\
+            Siphasher: {} | Custom: {} | Fast: {} | Adaptive: {} \n",
+            run_count,
+            data_len,
+            siphasher_compute_units,
+            custom_compute_units,
+            fast_compute_units,
+            adaptive_compute_units
+        ));
+    }
+
+    for output in output {
+        println!("{}", output);
+    }
+}
+
+#[test]
+#[ignore]
+fn test_compare_cu_from_keccak_hash() {
+    let program_id = Address::from(TEST_PROGRAM_ID);
+    let mollusk = Mollusk::new(&program_id, "test_program");
+
+    let mut output = Vec::with_capacity(INPUT_COUNT);
+
+    for run_count in 0..INPUT_COUNT {
+        let data = random_input_data();
+        let data_len = data.len();
+
+        let instruction = Instruction {
+            program_id,
+            data: std::iter::once(6u8)
+                .chain(data.into_iter())
+                .collect::<Vec<u8>>(),
+            accounts: vec![],
+        };
+
+        let result =
+            mollusk.process_and_validate_instruction(&instruction, &[], &[Check::success()]);
+
+        let (
+            siphasher_compute_units,
+            custom_compute_units,
+            keccak_compute_units,
+            fast_compute_units,
+        ) = (
+            u64::from_le_bytes(result.return_data[..8].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[8..16].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[16..24].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[24..].try_into().unwrap()),
+        );
+
+        output.push(format!(
+            "Run {}: \n\
+            Data length: {} \n\
+            Siphasher: {} | Custom: {} | Keccak: {} | Fast: {} \n",
+            run_count,
+            data_len,
+            siphasher_compute_units,
+            custom_compute_units,
+            keccak_compute_units,
+            fast_compute_units
+        ));
+    }
+
+    for output in output {
+        println!("{}", output);
+    }
+}
+
+#[test]
+#[ignore]
+fn test_compare_cu_from_software_hash() {
+    let program_id = Address::from(TEST_PROGRAM_ID);
+    let mollusk = Mollusk::new(&program_id, "test_program");
+
+    let mut output = Vec::with_capacity(UPPER_BOUND - LOWER_BOUND + 1);
+
+    // Every length is measured, since the cost of `sha2` goes up with every
+    // 64 byte block while the syscall goes up with every 2 bytes
+    for data_len in LOWER_BOUND..=UPPER_BOUND {
+        let data = random_input_data_with_len(data_len, &mut rand::rng());
+
+        let instruction = Instruction {
+            program_id,
+            data: std::iter::once(9u8)
+                .chain(data.into_iter())
+                .collect::<Vec<u8>>(),
+            accounts: vec![],
+        };
+
+        let result =
+            mollusk.process_and_validate_instruction(&instruction, &[], &[Check::success()]);
+
+        let (
+            siphasher_compute_units,
+            custom_compute_units,
+            software_compute_units,
+            fast_compute_units,
+        ) = (
+            u64::from_le_bytes(result.return_data[..8].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[8..16].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[16..24].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[24..].try_into().unwrap()),
+        );
+
+        output.push(format!(
+            "Data length: {} \n\
+            Siphasher: {} | Custom: {} | Software: {} | Fast: {} \n",
+            data_len,
+            siphasher_compute_units,
+            custom_compute_units,
+            software_compute_units,
+            fast_compute_units
+        ));
+    }
+
+    for output in output {
+        println!("{}", output);
+    }
+}
+
+#[test]
+#[ignore]
+#[cfg(feature = "blake3")]
+fn test_compare_cu_from_blake3_hash() {
+    let program_id = Address::from(TEST_PROGRAM_ID);
+    let mollusk = Mollusk::new(&program_id, "test_program");
+
+    let mut output = Vec::with_capacity(INPUT_COUNT);
+
+    for run_count in 0..INPUT_COUNT {
+        let data = random_input_data();
+        let data_len = data.len();
+
+        let instruction = Instruction {
+            program_id,
+            data: std::iter::once(7u8)
+                .chain(data.into_iter())
+                .collect::<Vec<u8>>(),
+            accounts: vec![],
+        };
+
+        let result =
+            mollusk.process_and_validate_instruction(&instruction, &[], &[Check::success()]);
+
+        let (
+            siphasher_compute_units,
+            custom_compute_units,
+            blake3_compute_units,
+            fast_compute_units,
+        ) = (
+            u64::from_le_bytes(result.return_data[..8].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[8..16].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[16..24].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[24..].try_into().unwrap()),
         );
 
         output.push(format!(
             "Run {}: \n\
             Data length: {} \n\
-            Siphasher: {} | Custom: {} \n",
-            run_count, data_len, siphasher_compute_units, custom_compute_units
+            Siphasher: {} | Custom: {} | Blake3: {} | Fast: {} \n",
+            run_count,
+            data_len,
+            siphasher_compute_units,
+            custom_compute_units,
+            blake3_compute_units,
+            fast_compute_units
         ));
     }
 
@@ -135,18 +482,19 @@ fn test_compare_cu_from_hash_set() {
         output.push(format!("Run {}: \n", run_count));
 
         for data_len in data_lens {
-            let (siphasher_compute_units, custom_compute_units) = (
+            let (siphasher_compute_units, custom_compute_units, fast_compute_units) = (
                 u64::from_le_bytes(return_data[..8].try_into().unwrap()),
                 u64::from_le_bytes(return_data[8..16].try_into().unwrap()),
+                u64::from_le_bytes(return_data[16..24].try_into().unwrap()),
             );
 
             output.push(format!(
                 "Data length: {} \n\
-                Siphasher: {} | Custom: {} \n",
-                data_len, siphasher_compute_units, custom_compute_units
+                Siphasher: {} | Custom: {} | Fast: {} \n",
+                data_len, siphasher_compute_units, custom_compute_units, fast_compute_units
             ));
 
-            return_data = &return_data[16..];
+            return_data = &return_data[24..];
         }
     }
 
@@ -192,18 +540,19 @@ fn test_compare_cu_from_hash_map() {
         output.push(format!("Run {}: \n", run_count));
 
         for data_len in data_lens {
-            let (siphasher_compute_units, custom_compute_units) = (
+            let (siphasher_compute_units, custom_compute_units, fast_compute_units) = (
                 u64::from_le_bytes(return_data[..8].try_into().unwrap()),
                 u64::from_le_bytes(return_data[8..16].try_into().unwrap()),
+                u64::from_le_bytes(return_data[16..24].try_into().unwrap()),
             );
 
             output.push(format!(
                 "Data length: {} \n\
-                Siphasher: {} | Custom: {} \n",
-                data_len, siphasher_compute_units, custom_compute_units
+                Siphasher: {} | Custom: {} | Fast: {} \n",
+                data_len, siphasher_compute_units, custom_compute_units, fast_compute_units
             ));
 
-            return_data = &return_data[16..];
+            return_data = &return_data[24..];
         }
     }
 
@@ -212,6 +561,148 @@ fn test_compare_cu_from_hash_map() {
     }
 }
 
+const LARGE_KEY_LENS: [usize; 8] = [32, 64, 128, 256, 512, 1024, 2048, 4096];
+
+#[test]
+#[ignore]
+fn test_compare_cu_from_bounded_hash_map() {
+    let program_id = Address::from(TEST_PROGRAM_ID);
+    let mollusk = Mollusk::new(&program_id, "test_program");
+
+    let mut output = Vec::with_capacity(LARGE_KEY_LENS.len());
+
+    // The cost of the bounded lookup should stop growing past twice the
+    // bound the program uses
+    for data_len in LARGE_KEY_LENS {
+        let data = random_input_data_with_len(data_len, &mut rand::rng());
+
+        let instruction = Instruction {
+            program_id,
+            data: std::iter::once(10u8)
+                .chain(data.into_iter())
+                .collect::<Vec<u8>>(),
+            accounts: vec![],
+        };
+
+        let result =
+            mollusk.process_and_validate_instruction(&instruction, &[], &[Check::success()]);
+
+        let (custom_compute_units, bounded_compute_units) = (
+            u64::from_le_bytes(result.return_data[..8].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[8..].try_into().unwrap()),
+        );
+
+        output.push(format!(
+            "Data length: {} \n\
+            Custom map: {} | Bounded map: {} \n",
+            data_len, custom_compute_units, bounded_compute_units
+        ));
+    }
+
+    for output in output {
+        println!("{}", output);
+    }
+}
+
+#[test]
+#[ignore]
+fn test_compare_cu_from_buffered_hash_map() {
+    let program_id = Address::from(TEST_PROGRAM_ID);
+    let mollusk = Mollusk::new(&program_id, "test_program");
+
+    let mut output = Vec::with_capacity(UPPER_BOUND - LOWER_BOUND + 1);
+
+    // The buffered lookup should stay at a single syscall until the two
+    // halves and their length prefixes no longer fit in its buffer
+    for data_len in LOWER_BOUND..=UPPER_BOUND {
+        let data = random_input_data_with_len(data_len, &mut rand::rng());
+
+        let instruction = Instruction {
+            program_id,
+            data: std::iter::once(11u8)
+                .chain(data.into_iter())
+                .collect::<Vec<u8>>(),
+            accounts: vec![],
+        };
+
+        let result =
+            mollusk.process_and_validate_instruction(&instruction, &[], &[Check::success()]);
+
+        let (custom_compute_units, buffered_compute_units) = (
+            u64::from_le_bytes(result.return_data[..8].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[8..].try_into().unwrap()),
+        );
+
+        output.push(format!(
+            "Data length: {} \n\
+            Custom map: {} | Buffered map: {} \n",
+            data_len, custom_compute_units, buffered_compute_units
+        ));
+    }
+
+    for output in output {
+        println!("{}", output);
+    }
+}
+
+#[test]
+#[ignore]
+fn test_adaptive_threshold() {
+    let program_id = Address::from(TEST_PROGRAM_ID);
+    let mollusk = Mollusk::new(&program_id, "test_program");
+
+    let mut output = Vec::with_capacity(UPPER_BOUND - LOWER_BOUND + 1);
+    let mut crossover = None;
+
+    for data_len in LOWER_BOUND..=UPPER_BOUND {
+        let data = random_input_data_with_len(data_len, &mut rand::rng());
+
+        let instruction = Instruction {
+            program_id,
+            data: std::iter::once(2u8)
+                .chain(data.into_iter())
+                .collect::<Vec<u8>>(),
+            accounts: vec![],
+        };
+
+        let result =
+            mollusk.process_and_validate_instruction(&instruction, &[], &[Check::success()]);
+
+        let (custom_compute_units, mixer_compute_units) = (
+            u64::from_le_bytes(result.return_data[8..16].try_into().unwrap()),
+            u64::from_le_bytes(result.return_data[32..].try_into().unwrap()),
+        );
+
+        // A `[u8]` key writes its length before its bytes
+        let key_len = data_len + core::mem::size_of::<usize>();
+
+        if crossover.is_none() && custom_compute_units < mixer_compute_units {
+            crossover = Some(key_len);
+        }
+
+        output.push(format!(
+            "Key length: {} \n\
+            Custom: {} | Mixer: {} \n",
+            key_len, custom_compute_units, mixer_compute_units
+        ));
+    }
+
+    for output in output {
+        println!("{}", output);
+    }
+
+    let crossover = crossover.expect("the mixer is cheaper for every key length");
+
+    println!("Crossover: {} bytes", crossover);
+
+    assert!(
+        crossover.abs_diff(svm_hasher::DEFAULT_ADAPTIVE_THRESHOLD) <= 8,
+        "DEFAULT_ADAPTIVE_THRESHOLD is {}, the syscall gets cheaper at {} bytes",
+        svm_hasher::DEFAULT_ADAPTIVE_THRESHOLD,
+        crossover
+    );
+}
+
 #[test]
 #[ignore]
 fn test_compare_cu_from_all() {
@@ -249,44 +740,47 @@ fn test_compare_cu_from_all() {
         output.push(format!("Run {}: \n", run_count));
 
         for data_len in data_lens {
-            let (siphasher_compute_units, custom_compute_units) = (
+            let (siphasher_compute_units, custom_compute_units, fast_compute_units) = (
                 u64::from_le_bytes(return_data[..8].try_into().unwrap()),
                 u64::from_le_bytes(return_data[8..16].try_into().unwrap()),
+                u64::from_le_bytes(return_data[16..24].try_into().unwrap()),
             );
 
             output.push(format!(
                 "Data length: {} \n\
-                Siphasher: {} | Custom: {} \n",
-                data_len, siphasher_compute_units, custom_compute_units
+                Siphasher: {} | Custom: {} | Fast: {} \n",
+                data_len, siphasher_compute_units, custom_compute_units, fast_compute_units
             ));
 
-            return_data = &return_data[16..];
+            return_data = &return_data[24..];
 
-            let (siphasher_compute_units, custom_compute_units) = (
+            let (siphasher_compute_units, custom_compute_units, fast_compute_units) = (
                 u64::from_le_bytes(return_data[..8].try_into().unwrap()),
                 u64::from_le_bytes(return_data[8..16].try_into().unwrap()),
+                u64::from_le_bytes(return_data[16..24].try_into().unwrap()),
             );
 
             output.push(format!(
                 "Data length: {} \n\
-                Siphasher set: {} | Custom set: {} \n",
-                data_len, siphasher_compute_units, custom_compute_units
+                Siphasher set: {} | Custom set: {} | Fast set: {} \n",
+                data_len, siphasher_compute_units, custom_compute_units, fast_compute_units
             ));
 
-            return_data = &return_data[16..];
+            return_data = &return_data[24..];
 
-            let (siphasher_compute_units, custom_compute_units) = (
+            let (siphasher_compute_units, custom_compute_units, fast_compute_units) = (
                 u64::from_le_bytes(return_data[..8].try_into().unwrap()),
                 u64::from_le_bytes(return_data[8..16].try_into().unwrap()),
+                u64::from_le_bytes(return_data[16..24].try_into().unwrap()),
             );
 
             output.push(format!(
                 "Data length: {} \n\
-                Siphasher map: {} | Custom map: {} \n",
-                data_len, siphasher_compute_units, custom_compute_units
+                Siphasher map: {} | Custom map: {} | Fast map: {} \n",
+                data_len, siphasher_compute_units, custom_compute_units, fast_compute_units
             ));
 
-            return_data = &return_data[16..];
+            return_data = &return_data[24..];
         }
     }
 